@@ -0,0 +1,783 @@
+use crate::{BigInt, BigIntError};
+
+use num_traits::{Pow, ToPrimitive};
+
+use std::cmp::Ordering;
+use std::fmt::{self, Binary, Display, LowerHex, UpperHex};
+use std::ops::*;
+use std::str::FromStr;
+
+// magnitude engine shared with BigInt, digits are stored most significant first
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BigUint {
+    pub(crate) numbers: Vec<u8>,
+}
+
+impl Default for BigUint {
+    fn default() -> Self {
+        BigUint { numbers: vec![0] }
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = BigIntError;
+    fn from_str(string_of_numbers: &str) -> Result<Self, Self::Err> {
+        BigInt::from_str(string_of_numbers)?.try_into()
+    }
+}
+
+macro_rules! from_uint_biguint {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for BigUint{
+                fn from(mut original_number: $t) -> Self {
+
+                //zero edgecase
+                if original_number == 0 {
+                    return BigUint::default();
+                }
+
+                let mut numbers = Vec::new();
+
+                //transformation of digits
+                while original_number != 0{
+                    numbers.push((original_number % 10).to_u8().unwrap());
+                    original_number /= 10;
+                }
+
+                numbers.reverse();
+
+                BigUint { numbers }
+                }
+            }
+        )*
+    }
+}
+
+from_uint_biguint!(u8, u16, u32, u64, u128, usize);
+
+impl TryFrom<BigInt> for BigUint {
+    type Error = BigIntError;
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        if !value.positive {
+            return Err(BigIntError::NegativeNumber);
+        }
+
+        Ok(value.magnitude)
+    }
+}
+
+impl TryFrom<&BigInt> for BigUint {
+    type Error = BigIntError;
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        BigUint::try_from(value.clone())
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt::from_magnitude(true, magnitude)
+    }
+}
+
+impl From<&BigUint> for BigInt {
+    fn from(magnitude: &BigUint) -> Self {
+        BigInt::from(magnitude.clone())
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        if f.sign_plus() {
+            output.push('+');
+        }
+
+        for digit in &self.numbers {
+            output.push_str(&digit.to_string());
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl Binary for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Binary::fmt(&BigInt::from(self), f)
+    }
+}
+
+impl UpperHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = BigInt::from(self).create_hexa_string(f, true);
+
+        write!(f, "{output}")
+    }
+}
+
+impl LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = BigInt::from(self).create_hexa_string(f, false);
+
+        write!(f, "{output}")
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.numbers
+            .len()
+            .cmp(&other.numbers.len())
+            .then_with(|| self.numbers.cmp(&other.numbers))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+macro_rules! compare_with_uint {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<$t> for BigUint{
+                fn eq(&self, other: &$t) -> bool {
+                    self == &BigUint::from(*other)
+                }
+            }
+
+            impl PartialOrd<$t> for BigUint{
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    self.partial_cmp(&BigUint::from(*other))
+                }
+            }
+        )*
+    };
+}
+
+compare_with_uint!(u8, u16, u32, u64, u128, usize);
+
+impl<T> Add<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        self.add_magnitude(&rhs.into())
+    }
+}
+
+assign_trait!(BigUint, AddAssign, add_assign, add);
+
+impl<T> Sub<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        self.checked_sub(&rhs.into())
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+assign_trait!(BigUint, SubAssign, sub_assign, sub);
+
+impl<T> Mul<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        self.mul_magnitude(&rhs.into())
+    }
+}
+
+assign_trait!(BigUint, MulAssign, mul_assign, mul);
+
+impl<T> Div<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        let right: BigUint = rhs.into();
+
+        if right == 0_u8 {
+            panic!("division by zero!");
+        }
+
+        self.divide_with_remainder(&right).0
+    }
+}
+
+assign_trait!(BigUint, DivAssign, div_assign, div);
+
+impl<T> Rem<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn rem(self, rhs: T) -> Self::Output {
+        let right: BigUint = rhs.into();
+
+        if right == 0_u8 {
+            panic!("division by zero!");
+        }
+
+        self.divide_with_remainder(&right).1
+    }
+}
+
+assign_trait!(BigUint, RemAssign, rem_assign, rem);
+
+impl<T> Pow<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+
+    fn pow(self, rhs: T) -> Self::Output {
+        let right: BigUint = rhs.into();
+
+        if self == 0_u8 || self == 1_u8 || right == 1_u8 {
+            return self;
+        }
+        if right == 0_u8 {
+            return 1_u8.into();
+        }
+
+        let mut result: BigUint = 1_u8.into();
+
+        //square and multiply from the most significant bit
+        for bit in right.to_binary() {
+            result = result.mul_magnitude(&result);
+
+            if bit {
+                result = result.mul_magnitude(&self);
+            }
+        }
+
+        result
+    }
+}
+
+impl<T> BitAnd<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn bitand(self, rhs: T) -> Self::Output {
+        BigUint::from_binary(&self.combine_binary(&rhs.into(), |left, right| left & right))
+    }
+}
+
+assign_trait!(BigUint, BitAndAssign, bitand_assign, bitand);
+
+impl<T> BitOr<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn bitor(self, rhs: T) -> Self::Output {
+        BigUint::from_binary(&self.combine_binary(&rhs.into(), |left, right| left | right))
+    }
+}
+
+assign_trait!(BigUint, BitOrAssign, bitor_assign, bitor);
+
+impl<T> BitXor<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn bitxor(self, rhs: T) -> Self::Output {
+        BigUint::from_binary(&self.combine_binary(&rhs.into(), |left, right| left ^ right))
+    }
+}
+
+assign_trait!(BigUint, BitXorAssign, bitxor_assign, bitxor);
+
+impl<T> Shl<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn shl(self, rhs: T) -> Self::Output {
+        let right: BigUint = rhs.into();
+
+        if right == 0_u8 {
+            return self;
+        }
+
+        let base: BigUint = 2_u8.into();
+
+        self * base.pow(right)
+    }
+}
+
+assign_trait!(BigUint, ShlAssign, shl_assign, shl);
+
+impl<T> Shr<T> for BigUint
+where
+    T: Into<BigUint>,
+{
+    type Output = Self;
+    fn shr(self, rhs: T) -> Self::Output {
+        let right: BigUint = rhs.into();
+
+        if right == 0_u8 {
+            return self;
+        }
+
+        let base: BigUint = 2_u8.into();
+
+        self / base.pow(right)
+    }
+}
+
+assign_trait!(BigUint, ShrAssign, shr_assign, shr);
+
+impl BigUint {
+    pub fn new() -> BigUint {
+        BigUint::default()
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if self < other {
+            return None;
+        }
+
+        Some(self.sub_magnitude(other))
+    }
+
+    // strips leading zeros, no digits at all means zero
+    pub(crate) fn from_digits(mut numbers: Vec<u8>) -> BigUint {
+        let leading_zeros = numbers
+            .iter()
+            .take_while(|&&digit| digit == 0)
+            .count()
+            .min(numbers.len().saturating_sub(1));
+        numbers.drain(..leading_zeros);
+
+        if numbers.is_empty() {
+            return BigUint::default();
+        }
+
+        BigUint { numbers }
+    }
+
+    // None when it doesn't fit
+    pub(crate) fn to_u128(&self) -> Option<u128> {
+        self.numbers.iter().try_fold(0_u128, |result, &digit| {
+            result.checked_mul(10)?.checked_add(digit as u128)
+        })
+    }
+
+    pub(crate) fn is_even(&self) -> bool {
+        self.numbers.last().unwrap().is_multiple_of(2)
+    }
+
+    pub(crate) fn add_magnitude(&self, other: &BigUint) -> BigUint {
+        let mut left = self.numbers.iter().rev();
+        let mut right = other.numbers.iter().rev();
+
+        let mut result = Vec::with_capacity(self.numbers.len().max(other.numbers.len()) + 1);
+        let mut carry = 0;
+
+        loop {
+            let (left_digit, right_digit) = match (left.next(), right.next()) {
+                (None, None) => break,
+                (left_digit, right_digit) => (left_digit.unwrap_or(&0), right_digit.unwrap_or(&0)),
+            };
+
+            let new_number = left_digit + right_digit + carry;
+            result.push(new_number % 10);
+            carry = new_number / 10;
+        }
+
+        if carry != 0 {
+            result.push(carry);
+        }
+
+        result.reverse();
+
+        BigUint { numbers: result }
+    }
+
+    // expects self >= other
+    pub(crate) fn sub_magnitude(&self, other: &BigUint) -> BigUint {
+        let mut result = self.clone();
+        result.subtract_in_place(other);
+        result
+    }
+
+    fn subtract_in_place(&mut self, other: &BigUint) {
+        let mut right = other.numbers.iter().rev();
+        let mut carry = 0;
+
+        for digit in self.numbers.iter_mut().rev() {
+            let mut new_number = *digit as i8 - *right.next().unwrap_or(&0) as i8 - carry;
+
+            if new_number < 0 {
+                new_number += 10;
+                carry = 1;
+            } else {
+                carry = 0;
+            }
+
+            *digit = new_number as u8;
+        }
+
+        let leading_zeros = self
+            .numbers
+            .iter()
+            .take_while(|&&digit| digit == 0)
+            .count()
+            .min(self.numbers.len() - 1);
+        self.numbers.drain(..leading_zeros);
+    }
+
+    pub(crate) fn mul_magnitude(&self, other: &BigUint) -> BigUint {
+        //X * 0 edgecase
+        if *self == 0_u8 || *other == 0_u8 {
+            return BigUint::default();
+        }
+
+        BigUint::from_limbs(&multiply_limbs(&self.to_limbs(), &other.to_limbs()))
+    }
+
+    // long division on 10^9 limbs, panics on zero divisor
+    pub(crate) fn divide_with_remainder(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        if *divisor == 0_u8 {
+            panic!("division by zero!");
+        }
+
+        //smaller dividend edgecase
+        if self < divisor {
+            return (BigUint::default(), self.clone());
+        }
+
+        let (quotient, remainder) = divide_limbs(&self.to_limbs(), &divisor.to_limbs());
+
+        (
+            BigUint::from_limbs(&quotient),
+            BigUint::from_limbs(&remainder),
+        )
+    }
+
+    // base 10^9, least significant limb first
+    fn to_limbs(&self) -> Vec<u64> {
+        self.numbers
+            .rchunks(LIMB_DIGITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |limb, &digit| limb * 10 + digit as u64)
+            })
+            .collect()
+    }
+
+    fn from_limbs(limbs: &[u64]) -> BigUint {
+        let mut numbers = Vec::with_capacity(limbs.len() * LIMB_DIGITS);
+
+        for &limb in limbs.iter().rev() {
+            let mut limb = limb;
+            let mut digits = [0; LIMB_DIGITS];
+            for digit in digits.iter_mut().rev() {
+                *digit = (limb % 10) as u8;
+                limb /= 10;
+            }
+            numbers.extend_from_slice(&digits);
+        }
+
+        BigUint::from_digits(numbers)
+    }
+
+    // most significant bit first
+    pub(crate) fn to_binary(&self) -> Vec<bool> {
+        if *self == 0_u8 {
+            return vec![false];
+        }
+
+        let mut numbers = self.numbers.clone();
+        let mut start = 0;
+        let mut binary = vec![];
+
+        //halve until nothing is left
+        while start < numbers.len() {
+            binary.push(numbers.last().unwrap() % 2 == 1);
+
+            let mut remainder = 0;
+            for digit in numbers[start..].iter_mut() {
+                let current = remainder * 10 + *digit;
+                *digit = current / 2;
+                remainder = current % 2;
+            }
+
+            while start < numbers.len() && numbers[start] == 0 {
+                start += 1;
+            }
+        }
+
+        binary.reverse();
+
+        binary
+    }
+
+    pub(crate) fn from_binary(binary: &[bool]) -> BigUint {
+        //least significant digit first while doubling
+        let mut numbers: Vec<u8> = vec![0];
+
+        for &bit in binary {
+            let mut carry = bit as u8;
+
+            for digit in numbers.iter_mut() {
+                let new_number = *digit * 2 + carry;
+                *digit = new_number % 10;
+                carry = new_number / 10;
+            }
+
+            if carry != 0 {
+                numbers.push(carry);
+            }
+        }
+
+        numbers.reverse();
+
+        BigUint::from_digits(numbers)
+    }
+
+    pub(crate) fn to_hexa_vec(&self) -> Vec<char> {
+        let binary = self.to_binary();
+
+        binary
+            .rchunks(4)
+            .rev()
+            .map(|chunk| {
+                let number = chunk.iter().fold(0, |number, &bit| number * 2 + bit as u32);
+                std::char::from_digit(number, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+
+    // bitwise combination of both magnitudes, most significant bit first
+    pub(crate) fn combine_binary<F>(&self, other: &BigUint, bit_operation: F) -> Vec<bool>
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        let mut left = self.to_binary();
+        let mut right = other.to_binary();
+
+        left.reverse();
+        right.reverse();
+
+        let length = left.len().max(right.len());
+        left.resize(length, false);
+        right.resize(length, false);
+
+        left.into_iter()
+            .zip(right)
+            .rev()
+            .map(|(left, right)| bit_operation(left, right))
+            .collect()
+    }
+}
+
+const LIMB_DIGITS: usize = 9;
+const LIMB_BASE: u64 = 1_000_000_000;
+const KARATSUBA_THRESHOLD: usize = 48;
+
+fn multiply_limbs(left: &[u64], right: &[u64]) -> Vec<u64> {
+    if left.len().min(right.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook_multiply(left, right);
+    }
+
+    //left = high * B^half + low, the same for right
+    let half = left.len().max(right.len()) / 2;
+    let (left_low, left_high) = left.split_at(half.min(left.len()));
+    let (right_low, right_high) = right.split_at(half.min(right.len()));
+
+    let low = multiply_limbs(left_low, right_low);
+    let high = multiply_limbs(left_high, right_high);
+    let middle = multiply_limbs(
+        &add_limbs(left_low, left_high),
+        &add_limbs(right_low, right_high),
+    );
+    let middle = subtract_limbs(&subtract_limbs(&middle, &low), &high);
+
+    let mut result = vec![0; left.len() + right.len() + 1];
+    add_limbs_at(&mut result, &low, 0);
+    add_limbs_at(&mut result, &middle, half);
+    add_limbs_at(&mut result, &high, 2 * half);
+
+    result
+}
+
+fn schoolbook_multiply(left: &[u64], right: &[u64]) -> Vec<u64> {
+    let mut result = vec![0; left.len() + right.len()];
+
+    for (position, &left_limb) in left.iter().enumerate() {
+        // *0 edgecase
+        if left_limb == 0 {
+            continue;
+        }
+
+        let mut carry = 0;
+        for (offset, &right_limb) in right.iter().enumerate() {
+            let current = result[position + offset] + left_limb * right_limb + carry;
+            result[position + offset] = current % LIMB_BASE;
+            carry = current / LIMB_BASE;
+        }
+        result[position + right.len()] += carry;
+    }
+
+    result
+}
+
+fn add_limbs(left: &[u64], right: &[u64]) -> Vec<u64> {
+    let mut result = left.to_vec();
+    result.resize(left.len().max(right.len()) + 1, 0);
+    add_limbs_at(&mut result, right, 0);
+
+    result
+}
+
+// result += limbs * B^offset, result has to be long enough for the carry
+fn add_limbs_at(result: &mut [u64], limbs: &[u64], offset: usize) {
+    let length = limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |top| top + 1);
+    let limbs = &limbs[..length];
+    let mut carry = 0;
+
+    for (position, &limb) in limbs.iter().enumerate() {
+        let current = result[offset + position] + limb + carry;
+        result[offset + position] = current % LIMB_BASE;
+        carry = current / LIMB_BASE;
+    }
+
+    let mut position = offset + limbs.len();
+    while carry != 0 {
+        let current = result[position] + carry;
+        result[position] = current % LIMB_BASE;
+        carry = current / LIMB_BASE;
+        position += 1;
+    }
+}
+
+// expects left >= right
+fn subtract_limbs(left: &[u64], right: &[u64]) -> Vec<u64> {
+    let mut result = left.to_vec();
+    let mut borrow = 0;
+
+    for (position, limb) in result.iter_mut().enumerate() {
+        let subtrahend = right.get(position).copied().unwrap_or(0) + borrow;
+        if *limb >= subtrahend {
+            *limb -= subtrahend;
+            borrow = 0;
+        } else {
+            *limb += LIMB_BASE - subtrahend;
+            borrow = 1;
+        }
+    }
+
+    result
+}
+
+// Knuth's algorithm D, expects dividend >= divisor > 0
+fn divide_limbs(dividend: &[u64], divisor: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let divisor_length = divisor.iter().rposition(|&limb| limb != 0).unwrap() + 1;
+    let divisor = &divisor[..divisor_length];
+
+    //single limb divisor edgecase
+    if divisor_length == 1 {
+        let mut quotient = vec![0; dividend.len()];
+        let mut remainder = 0;
+
+        for (position, &limb) in dividend.iter().enumerate().rev() {
+            let current = remainder * LIMB_BASE + limb;
+            quotient[position] = current / divisor[0];
+            remainder = current % divisor[0];
+        }
+
+        return (quotient, vec![remainder]);
+    }
+
+    //scale both so the top divisor limb is at least B / 2
+    let scale = LIMB_BASE / (divisor[divisor_length - 1] + 1);
+    let divisor = schoolbook_multiply(divisor, &[scale]);
+    let divisor = &divisor[..divisor_length];
+    let mut remainder = schoolbook_multiply(dividend, &[scale]);
+    remainder.push(0);
+
+    let top = divisor[divisor_length - 1];
+    let second = divisor[divisor_length - 2];
+    let mut quotient = vec![0; remainder.len() - divisor_length];
+
+    for position in (0..quotient.len()).rev() {
+        //estimate from the top two limbs, too large by at most 2
+        let current = remainder[position + divisor_length] * LIMB_BASE
+            + remainder[position + divisor_length - 1];
+        let mut estimate = current / top;
+        let mut rest = current % top;
+
+        while estimate >= LIMB_BASE
+            || estimate * second > rest * LIMB_BASE + remainder[position + divisor_length - 2]
+        {
+            estimate -= 1;
+            rest += top;
+            if rest >= LIMB_BASE {
+                break;
+            }
+        }
+
+        //remainder -= estimate * divisor at position
+        let mut carry = 0;
+        let mut borrow = 0;
+        for (offset, &limb) in divisor.iter().enumerate() {
+            let product = estimate * limb + carry;
+            carry = product / LIMB_BASE;
+            let subtrahend = product % LIMB_BASE + borrow;
+
+            if remainder[position + offset] >= subtrahend {
+                remainder[position + offset] -= subtrahend;
+                borrow = 0;
+            } else {
+                remainder[position + offset] += LIMB_BASE - subtrahend;
+                borrow = 1;
+            }
+        }
+
+        let subtrahend = carry + borrow;
+        if remainder[position + divisor_length] >= subtrahend {
+            remainder[position + divisor_length] -= subtrahend;
+        } else {
+            //estimate was one too large, add the divisor back
+            remainder[position + divisor_length] += LIMB_BASE - subtrahend;
+            estimate -= 1;
+
+            let mut carry = 0;
+            for (offset, &limb) in divisor.iter().enumerate() {
+                let current = remainder[position + offset] + limb + carry;
+                remainder[position + offset] = current % LIMB_BASE;
+                carry = current / LIMB_BASE;
+            }
+            remainder[position + divisor_length] =
+                (remainder[position + divisor_length] + carry) % LIMB_BASE;
+        }
+
+        quotient[position] = estimate;
+    }
+
+    //undo the scaling of the remainder
+    let (remainder, _) = divide_limbs(&remainder[..divisor_length], &[scale]);
+
+    (quotient, remainder)
+}
@@ -0,0 +1,134 @@
+use crate::float::bit_length;
+use crate::{BigInt, BigIntError, BigUint};
+
+// the sign flag is true unless the number is negative, like the positive field
+impl BigInt {
+    /// Sign and the big-endian magnitude, zero is [0].
+    pub fn to_bytes_be(&self) -> (bool, Vec<u8>) {
+        (self.positive, to_bytes(&self.abs(), 1))
+    }
+
+    /// Sign and the little-endian magnitude, zero is [0].
+    pub fn to_bytes_le(&self) -> (bool, Vec<u8>) {
+        let (positive, mut bytes) = self.to_bytes_be();
+        bytes.reverse();
+
+        (positive, bytes)
+    }
+
+    /// Sign and the big-endian magnitude in exactly `length` bytes, errors when it doesn't fit.
+    pub fn to_bytes_be_padded(&self, length: usize) -> Result<(bool, Vec<u8>), BigIntError> {
+        let bytes = to_bytes(&self.abs(), length);
+        if bytes.len() > length {
+            return Err(BigIntError::LargeNumber);
+        }
+
+        Ok((self.positive, bytes))
+    }
+
+    /// Sign and the little-endian magnitude in exactly `length` bytes, errors when it doesn't fit.
+    pub fn to_bytes_le_padded(&self, length: usize) -> Result<(bool, Vec<u8>), BigIntError> {
+        let (positive, mut bytes) = self.to_bytes_be_padded(length)?;
+        bytes.reverse();
+
+        Ok((positive, bytes))
+    }
+
+    /// From a sign and a big-endian magnitude, no bytes means zero.
+    pub fn from_bytes_be(positive: bool, bytes: &[u8]) -> BigInt {
+        BigInt::from_magnitude(positive, from_bytes(bytes))
+    }
+
+    /// From a sign and a little-endian magnitude, no bytes means zero.
+    pub fn from_bytes_le(positive: bool, bytes: &[u8]) -> BigInt {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+
+        BigInt::from_bytes_be(positive, &bytes)
+    }
+
+    /// Shortest big-endian two's complement, zero is [0].
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        //one bit more than the magnitude for the sign, -2^n still fits into n + 1 bits
+        let magnitude = if self.positive {
+            self.clone()
+        } else {
+            self.abs() - 1
+        };
+        let length = (bit_length(&magnitude) / 8 + 1) as usize;
+
+        to_bytes(&self.wrapping_to_bits(8 * length as u64), length)
+    }
+
+    /// Shortest little-endian two's complement, zero is [0].
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self.to_signed_bytes_be();
+        bytes.reverse();
+
+        bytes
+    }
+
+    /// Big-endian two's complement in exactly `length` bytes, errors when it doesn't fit.
+    pub fn to_signed_bytes_be_padded(&self, length: usize) -> Result<Vec<u8>, BigIntError> {
+        let bits = 8 * length as u64;
+
+        if self.truncate_signed(bits) != *self {
+            return Err(if self.positive {
+                BigIntError::LargeNumber
+            } else {
+                BigIntError::SmallNumber
+            });
+        }
+
+        Ok(to_bytes(&self.wrapping_to_bits(bits), length))
+    }
+
+    /// Little-endian two's complement in exactly `length` bytes, errors when it doesn't fit.
+    pub fn to_signed_bytes_le_padded(&self, length: usize) -> Result<Vec<u8>, BigIntError> {
+        let mut bytes = self.to_signed_bytes_be_padded(length)?;
+        bytes.reverse();
+
+        Ok(bytes)
+    }
+
+    /// From big-endian two's complement, no bytes means zero.
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> BigInt {
+        BigInt::from(from_bytes(bytes)).truncate_signed(8 * bytes.len() as u64)
+    }
+
+    /// From little-endian two's complement, no bytes means zero.
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> BigInt {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+
+        BigInt::from_signed_bytes_be(&bytes)
+    }
+}
+
+// big-endian bytes of a non-negative number, padded with zeros to at least length
+fn to_bytes(number: &BigInt, length: usize) -> Vec<u8> {
+    let mut bytes: Vec<u8> = if *number == 0 {
+        vec![]
+    } else {
+        number
+            .magnitude
+            .to_binary()
+            .rchunks(8)
+            .rev()
+            .map(|chunk| chunk.iter().fold(0, |byte, &bit| byte * 2 + bit as u8))
+            .collect()
+    };
+
+    if bytes.len() < length {
+        bytes.splice(0..0, std::iter::repeat_n(0, length - bytes.len()));
+    }
+
+    bytes
+}
+
+fn from_bytes(bytes: &[u8]) -> BigUint {
+    let binary: Vec<bool> = bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+        .collect();
+
+    BigUint::from_binary(&binary)
+}
@@ -0,0 +1,195 @@
+use crate::BigInt;
+
+use num_traits::Pow;
+
+// results are built from their prime factorization (Legendre's formula)
+// and multiplied back together with binary splitting, so the big
+// multiplications always get operands of similar size. Binomials with
+// only a few factors skip the sieve and divide a falling factorial instead
+impl BigInt {
+    pub fn factorial(n: u64) -> BigInt {
+        let factors = BigInt::primes_up_to(n)
+            .into_iter()
+            .map(|prime| (prime, BigInt::legendre(n, prime)))
+            .collect();
+
+        BigInt::product_of_prime_powers(factors)
+    }
+
+    // n * (n - 2) * (n - 4) * ...
+    pub fn double_factorial(n: u64) -> BigInt {
+        BigInt::split_product(0, n.div_ceil(2), &|index| BigInt::from(n - 2 * index))
+    }
+
+    pub fn binomial(n: u64, k: u64) -> BigInt {
+        if k > n {
+            return BigInt::default();
+        }
+
+        //few factors, n^(k) / k! needs no sieve up to n
+        let k = k.min(n - k);
+        if BigInt::is_small_part(k, n) {
+            return BigInt::falling_factorial(n, k) / BigInt::factorial(k);
+        }
+
+        let factors = BigInt::primes_up_to(n)
+            .into_iter()
+            .map(|prime| {
+                let exponent = BigInt::legendre(n, prime)
+                    - BigInt::legendre(k, prime)
+                    - BigInt::legendre(n - k, prime);
+                (prime, exponent)
+            })
+            .collect();
+
+        BigInt::product_of_prime_powers(factors)
+    }
+
+    // (k1 + k2 + ... + km)! / (k1! * k2! * ... * km!), panics when the sum overflows u64
+    pub fn multinomial(groups: &[u64]) -> BigInt {
+        let total = groups
+            .iter()
+            .try_fold(0_u64, |total, &group| total.checked_add(group))
+            .expect("multinomial total overflows u64!");
+
+        //one dominant group, binomial(total, rest) * multinomial(others)
+        if let Some(largest) = groups.iter().position(|group| total - group < *group) {
+            let rest = total - groups[largest];
+            if BigInt::is_small_part(rest, total) {
+                let others: Vec<u64> = groups
+                    .iter()
+                    .enumerate()
+                    .filter(|&(index, _)| index != largest)
+                    .map(|(_, &group)| group)
+                    .collect();
+
+                return BigInt::binomial(total, rest) * BigInt::multinomial(&others);
+            }
+        }
+
+        let factors = BigInt::primes_up_to(total)
+            .into_iter()
+            .map(|prime| {
+                let exponent = groups
+                    .iter()
+                    .fold(BigInt::legendre(total, prime), |exponent, &group| {
+                        exponent - BigInt::legendre(group, prime)
+                    });
+                (prime, exponent)
+            })
+            .collect();
+
+        BigInt::product_of_prime_powers(factors)
+    }
+
+    // binomial(2n, n) / (n + 1), panics when 2n overflows u64
+    pub fn catalan(n: u64) -> BigInt {
+        let doubled = n.checked_mul(2).expect("catalan index overflows u64!");
+
+        let factors = BigInt::primes_up_to(doubled)
+            .into_iter()
+            .map(|prime| {
+                let mut exponent =
+                    BigInt::legendre(doubled, prime) - 2 * BigInt::legendre(n, prime);
+
+                let mut rest = n + 1;
+                while rest.is_multiple_of(prime) {
+                    rest /= prime;
+                    exponent -= 1;
+                }
+
+                (prime, exponent)
+            })
+            .collect();
+
+        BigInt::product_of_prime_powers(factors)
+    }
+
+    // x * (x - 1) * ... * (x - k + 1)
+    pub fn falling_factorial<T>(x: T, k: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        let x: BigInt = x.into();
+
+        BigInt::split_product(0, k, &|index| x.clone() - index)
+    }
+
+    // x * (x + 1) * ... * (x + k - 1)
+    pub fn rising_factorial<T>(x: T, k: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        let x: BigInt = x.into();
+
+        BigInt::split_product(0, k, &|index| x.clone() + index)
+    }
+
+    // product of term(index) for index in start..end
+    fn split_product<F>(start: u64, end: u64, term: &F) -> BigInt
+    where
+        F: Fn(u64) -> BigInt,
+    {
+        match end.saturating_sub(start) {
+            0 => 1.into(),
+            1 => term(start),
+            length => {
+                let middle = start + length / 2;
+                BigInt::split_product(start, middle, term)
+                    * BigInt::split_product(middle, end, term)
+            }
+        }
+    }
+
+    fn product_of_prime_powers(factors: Vec<(u64, u64)>) -> BigInt {
+        let factors: Vec<(u64, u64)> = factors
+            .into_iter()
+            .filter(|&(_, exponent)| exponent != 0)
+            .collect();
+
+        BigInt::split_product(0, factors.len() as u64, &|index| {
+            let (prime, exponent) = factors[index as usize];
+            BigInt::from(prime).pow(exponent)
+        })
+    }
+
+    // a sieve up to total costs more than multiplying part factors directly
+    fn is_small_part(part: u64, total: u64) -> bool {
+        part < total / 16
+    }
+
+    // exponent of prime in n!
+    fn legendre(mut n: u64, prime: u64) -> u64 {
+        let mut exponent = 0;
+
+        while n != 0 {
+            n /= prime;
+            exponent += n;
+        }
+
+        exponent
+    }
+
+    fn primes_up_to(limit: u64) -> Vec<u64> {
+        if limit < 2 {
+            return vec![];
+        }
+
+        let mut composite = vec![false; limit as usize + 1];
+        let mut primes = vec![];
+
+        for number in 2..=limit as usize {
+            if composite[number] {
+                continue;
+            }
+
+            primes.push(number as u64);
+
+            for multiple in (number * number..=limit as usize).step_by(number) {
+                composite[multiple] = true;
+            }
+        }
+
+        primes
+    }
+}
@@ -0,0 +1,63 @@
+use crate::BigInt;
+use crate::decimal::shift_digits;
+use crate::elementary::{binary_split, chudnovsky};
+use crate::number_theory::isqrt;
+
+// guard digits of the first attempt, doubled while the last digit is still uncertain
+const FIRST_GUARD: u64 = 8;
+
+/// π * 10^n truncated, Chudnovsky series with binary splitting.
+pub fn pi_digits(n: u64) -> BigInt {
+    truncated_digits(n, |digits| {
+        //every term adds more than 14 digits
+        let (q, t) = chudnovsky(digits / 14 + 2);
+        let root = isqrt(&shift_digits(&BigInt::from(10005), 2 * digits));
+
+        root * 426880 * q / t
+    })
+}
+
+/// e * 10^n truncated, sum of 1 / k! with binary splitting.
+pub fn e_digits(n: u64) -> BigInt {
+    truncated_digits(n, |digits| {
+        let mut terms = 1;
+        let mut factorial_digits = 0.0;
+        while factorial_digits < (digits + 2) as f64 {
+            terms += 1;
+            factorial_digits += (terms as f64).log10();
+        }
+
+        let (_, q, b, t) = binary_split(0, terms, &|k| {
+            (
+                BigInt::from(1),
+                BigInt::from(k.max(1)),
+                BigInt::from(1),
+                BigInt::from(1),
+            )
+        });
+
+        shift_digits(&t, digits) / (b * q)
+    })
+}
+
+// the approximation of x * 10^digits is off by less than 2
+fn truncated_digits<F>(n: u64, approximation: F) -> BigInt
+where
+    F: Fn(u64) -> BigInt,
+{
+    let mut guard = FIRST_GUARD;
+
+    loop {
+        let value = approximation(n + guard);
+        let scale = shift_digits(&BigInt::from(1), guard);
+
+        let low = (value.clone() - 2) / scale.clone();
+        let high = (value + 2) / scale;
+
+        if low == high {
+            return low;
+        }
+
+        guard *= 2;
+    }
+}
@@ -0,0 +1,114 @@
+use crate::BigInt;
+use crate::number_theory::isqrt;
+
+/// Partial quotients of p / q, the first one is floor(p / q) and the rest are positive.
+/// Panics on a zero q.
+pub fn continued_fraction(p: &BigInt, q: &BigInt) -> Vec<BigInt> {
+    if *q == 0 {
+        panic!("division by zero!");
+    }
+
+    //a positive denominator keeps the quotients floored
+    let (mut numerator, mut denominator) = if *q < 0 {
+        (-p.clone(), -q.clone())
+    } else {
+        (p.clone(), q.clone())
+    };
+    let mut quotients = vec![];
+
+    while denominator != 0 {
+        let (mut quotient, mut remainder) =
+            BigInt::divide_with_remainder(numerator, denominator.clone());
+        if remainder < 0 {
+            quotient -= 1;
+            remainder += denominator.clone();
+        }
+
+        quotients.push(quotient);
+        numerator = std::mem::replace(&mut denominator, remainder);
+    }
+
+    quotients
+}
+
+/// floor(sqrt(n)) and the period of the continued fraction of sqrt(n), the period is empty
+/// when n is a perfect square. Panics on a negative n.
+pub fn sqrt_continued_fraction(n: &BigInt) -> (BigInt, Vec<BigInt>) {
+    if *n < 0 {
+        panic!("square root of a negative number!");
+    }
+
+    let root = isqrt(n);
+    let mut period = vec![];
+
+    //perfect square edgecase
+    if root.clone() * root.clone() == *n {
+        return (root, period);
+    }
+
+    //sqrt(n) = (m + sqrt(n)) / denominator at every step, the period ends with 2 * root
+    let (mut m, mut denominator, mut a) = (BigInt::default(), BigInt::from(1), root.clone());
+    while a != root.clone() * 2 {
+        m = denominator.clone() * a.clone() - m;
+        denominator = (n.clone() - m.clone() * m.clone()) / denominator;
+        a = (root.clone() + m.clone()) / denominator.clone();
+        period.push(a.clone());
+    }
+
+    (root, period)
+}
+
+/// p / q for the partial quotients, in lowest terms with a positive q.
+/// Panics on an empty continued fraction or a non-positive quotient after the first.
+pub fn from_continued_fraction(quotients: &[BigInt]) -> (BigInt, BigInt) {
+    assert!(
+        quotients.iter().skip(1).all(|quotient| *quotient > 0),
+        "non-positive partial quotient!"
+    );
+
+    Convergents::new(quotients.iter().cloned())
+        .last()
+        .expect("empty continued fraction!")
+}
+
+/// Convergents p / q of a continued fraction, works with infinite ones as well.
+#[derive(Clone, Debug)]
+pub struct Convergents<I> {
+    quotients: I,
+    p: (BigInt, BigInt),
+    q: (BigInt, BigInt),
+}
+
+impl<I> Convergents<I>
+where
+    I: Iterator<Item = BigInt>,
+{
+    pub fn new<T>(quotients: T) -> Convergents<I>
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Convergents {
+            quotients: quotients.into_iter(),
+            p: (BigInt::from(1), BigInt::default()),
+            q: (BigInt::default(), BigInt::from(1)),
+        }
+    }
+}
+
+impl<I> Iterator for Convergents<I>
+where
+    I: Iterator<Item = BigInt>,
+{
+    type Item = (BigInt, BigInt);
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.quotients.next()?;
+
+        //p_n = a_n * p_(n - 1) + p_(n - 2), the same for q
+        let p = a.clone() * self.p.0.clone() + self.p.1.clone();
+        let q = a * self.q.0.clone() + self.q.1.clone();
+        self.p = (p.clone(), std::mem::take(&mut self.p.0));
+        self.q = (q.clone(), std::mem::take(&mut self.q.0));
+
+        Some((p, q))
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{BigInt, BigIntError, BigRational, BigUint};
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::ops::*;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Away from zero.
+    Up,
+    /// Toward zero.
+    Down,
+    /// Toward positive infinity.
+    Ceiling,
+    /// Toward negative infinity.
+    Floor,
+    /// To nearest, ties away from zero.
+    HalfUp,
+    /// To nearest, ties toward zero.
+    HalfDown,
+    /// To nearest, ties to the even neighbour.
+    HalfEven,
+}
+
+// value is unscaled * 10^(-scale), the scale stays within ±MAX_SCALE
+#[derive(Clone, Default, Debug)]
+pub struct BigDecimal {
+    unscaled: BigInt,
+    scale: i64,
+}
+
+impl FromStr for BigDecimal {
+    type Err = BigIntError;
+    fn from_str(string_of_numbers: &str) -> Result<Self, Self::Err> {
+        //exponent "1.2e-7"
+        let (number, exponent) = match string_of_numbers.split_once(['e', 'E']) {
+            Some((number, exponent)) => (
+                number,
+                exponent.parse::<i64>().map_err(|_| BigIntError::NaN)?,
+            ),
+            None => (string_of_numbers, 0),
+        };
+
+        let positive = !number.starts_with('-');
+        let number = &number[!positive as usize..];
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer.chars().all(|char| char.is_ascii_digit())
+            || !fraction.chars().all(|char| char.is_ascii_digit())
+        {
+            return Err(BigIntError::NaN);
+        }
+
+        let numbers = integer
+            .chars()
+            .chain(fraction.chars())
+            .map(|char| char.to_digit(10).unwrap() as u8)
+            .collect();
+
+        //exponent edgecase, e.g. "1e-9223372036854775808" or "1e999999999999"
+        let scale = (fraction.len() as i64)
+            .checked_sub(exponent)
+            .filter(is_valid_scale)
+            .ok_or(BigIntError::LargeNumber)?;
+
+        Ok(BigDecimal {
+            unscaled: BigInt::from_magnitude(positive, BigUint::from_digits(numbers)),
+            scale,
+        })
+    }
+}
+
+impl From<BigInt> for BigDecimal {
+    fn from(unscaled: BigInt) -> Self {
+        BigDecimal { unscaled, scale: 0 }
+    }
+}
+
+impl From<&BigInt> for BigDecimal {
+    fn from(unscaled: &BigInt) -> Self {
+        BigDecimal::from(unscaled.clone())
+    }
+}
+
+macro_rules! from_primitive_decimal {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for BigDecimal{
+                fn from(original_number: $t) -> Self {
+                    BigDecimal::from(BigInt::from(original_number))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_decimal!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl From<BigDecimal> for BigRational {
+    fn from(decimal: BigDecimal) -> Self {
+        if decimal.scale <= 0 {
+            return BigRational::from(shift_digits(
+                &decimal.unscaled,
+                decimal.scale.unsigned_abs(),
+            ));
+        }
+
+        BigRational::new(
+            decimal.unscaled,
+            shift_digits(&BigInt::from(1), decimal.scale as u64),
+        )
+    }
+}
+
+impl Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        if !self.unscaled.positive {
+            output.push('-');
+        }
+        if f.sign_plus() && self.unscaled.positive {
+            output.push('+');
+        }
+
+        let mut digits: String = self
+            .unscaled
+            .magnitude
+            .numbers
+            .iter()
+            .map(|digit| char::from(b'0' + digit))
+            .collect();
+
+        if self.scale <= 0 {
+            //zero edgecase
+            if self.unscaled != 0 {
+                digits.push_str(&"0".repeat(self.scale.unsigned_abs() as usize));
+            }
+            output.push_str(&digits);
+        } else {
+            let scale = self.scale as usize;
+            if digits.len() <= scale {
+                digits.insert_str(0, &"0".repeat(scale - digits.len() + 1));
+            }
+            digits.insert(digits.len() - scale, '.');
+            output.push_str(&digits);
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl Hash for BigDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.unscaled.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (left, right) = BigDecimal::align(self, other);
+
+        left.partial_cmp(&right).unwrap()
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> Self::Output {
+        BigDecimal {
+            unscaled: -self.unscaled,
+            scale: self.scale,
+        }
+    }
+}
+
+impl<T> Add<T> for BigDecimal
+where
+    T: Into<BigDecimal>,
+{
+    type Output = BigDecimal;
+    fn add(self, right: T) -> Self::Output {
+        let right = right.into();
+        let (left_unscaled, right_unscaled) = BigDecimal::align(&self, &right);
+
+        BigDecimal {
+            unscaled: left_unscaled + right_unscaled,
+            scale: self.scale.max(right.scale),
+        }
+    }
+}
+
+impl<T> Sub<T> for BigDecimal
+where
+    T: Into<BigDecimal>,
+{
+    type Output = BigDecimal;
+    fn sub(self, right: T) -> Self::Output {
+        self + (-right.into())
+    }
+}
+
+impl<T> Mul<T> for BigDecimal
+where
+    T: Into<BigDecimal>,
+{
+    type Output = BigDecimal;
+    fn mul(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        BigDecimal {
+            unscaled: self.unscaled * right.unscaled,
+            scale: checked_scale(self.scale.checked_add(right.scale)),
+        }
+    }
+}
+
+assign_trait!(BigDecimal, AddAssign, add_assign, add);
+assign_trait!(BigDecimal, SubAssign, sub_assign, sub);
+assign_trait!(BigDecimal, MulAssign, mul_assign, mul);
+
+impl BigDecimal {
+    /// Bound on the scale, Display and aligning two values allocate one digit per unit of scale.
+    pub const MAX_SCALE: i64 = 1_000_000;
+
+    /// Creates `unscaled * 10^(-scale)`, panics when the scale is beyond ±MAX_SCALE.
+    pub fn new<T>(unscaled: T, scale: i64) -> BigDecimal
+    where
+        T: Into<BigInt>,
+    {
+        BigDecimal {
+            unscaled: unscaled.into(),
+            scale: checked_scale(Some(scale)),
+        }
+    }
+
+    pub fn unscaled(&self) -> &BigInt {
+        &self.unscaled
+    }
+
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Rescales the number, rounding with `rounding` when digits are dropped.
+    /// Panics when the scale is beyond ±MAX_SCALE.
+    pub fn with_scale(&self, scale: i64, rounding: RoundingMode) -> BigDecimal {
+        let scale = checked_scale(Some(scale));
+        let unscaled = if scale >= self.scale {
+            shift_digits(&self.unscaled, scale.abs_diff(self.scale))
+        } else {
+            divide_rounded(
+                &self.unscaled,
+                &shift_digits(&BigInt::from(1), scale.abs_diff(self.scale)),
+                rounding,
+            )
+        };
+
+        BigDecimal { unscaled, scale }
+    }
+
+    /// Divides to `scale` digits after the decimal point, panics on a zero divisor
+    /// or a scale beyond ±MAX_SCALE.
+    pub fn divide(&self, divisor: &BigDecimal, scale: i64, rounding: RoundingMode) -> BigDecimal {
+        if divisor.unscaled == 0 {
+            panic!("division by zero!");
+        }
+
+        let scale = checked_scale(Some(scale));
+        let shift = scale
+            .checked_sub(self.scale)
+            .and_then(|shift| shift.checked_add(divisor.scale))
+            .expect("scale out of range!");
+        let unscaled = if shift >= 0 {
+            divide_rounded(
+                &shift_digits(&self.unscaled, shift.unsigned_abs()),
+                &divisor.unscaled,
+                rounding,
+            )
+        } else {
+            divide_rounded(
+                &self.unscaled,
+                &shift_digits(&divisor.unscaled, shift.unsigned_abs()),
+                rounding,
+            )
+        };
+
+        BigDecimal { unscaled, scale }
+    }
+
+    /// Removes trailing zeros from the unscaled value, as long as the scale stays within ±MAX_SCALE.
+    pub fn normalize(&self) -> BigDecimal {
+        //zero edgecase
+        if self.unscaled == 0 {
+            return BigDecimal::default();
+        }
+
+        let numbers = &self.unscaled.magnitude.numbers;
+        let trailing_zeros = numbers
+            .iter()
+            .rev()
+            .take_while(|&&digit| digit == 0)
+            .count()
+            .min(self.scale.abs_diff(-BigDecimal::MAX_SCALE) as usize);
+
+        BigDecimal {
+            unscaled: BigInt::from_magnitude(
+                self.unscaled.positive,
+                BigUint::from_digits(numbers[..numbers.len() - trailing_zeros].to_vec()),
+            ),
+            scale: self.scale - trailing_zeros as i64,
+        }
+    }
+
+    fn align(left: &BigDecimal, right: &BigDecimal) -> (BigInt, BigInt) {
+        let scale = left.scale.max(right.scale);
+
+        (
+            shift_digits(&left.unscaled, scale.abs_diff(left.scale)),
+            shift_digits(&right.unscaled, scale.abs_diff(right.scale)),
+        )
+    }
+}
+
+fn is_valid_scale(scale: &i64) -> bool {
+    (-BigDecimal::MAX_SCALE..=BigDecimal::MAX_SCALE).contains(scale)
+}
+
+// None stands for an overflowed scale computation
+fn checked_scale(scale: Option<i64>) -> i64 {
+    scale.filter(is_valid_scale).expect("scale out of range!")
+}
+
+// number * 10^digits by appending decimal digits
+pub(crate) fn shift_digits(number: &BigInt, digits: u64) -> BigInt {
+    //zero edgecase
+    if *number == 0 || digits == 0 {
+        return number.clone();
+    }
+
+    let mut numbers = number.magnitude.numbers.clone();
+    numbers.resize(numbers.len() + digits as usize, 0);
+
+    BigInt::from_magnitude(number.positive, BigUint { numbers })
+}
+
+pub(crate) fn divide_rounded(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    rounding: RoundingMode,
+) -> BigInt {
+    let (quotient, remainder) =
+        BigInt::divide_with_remainder(numerator.clone(), denominator.clone());
+
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let negative = numerator.positive != denominator.positive;
+    let away = if negative {
+        quotient.clone() - 1
+    } else {
+        quotient.clone() + 1
+    };
+
+    let round_away = match rounding {
+        RoundingMode::Up => true,
+        RoundingMode::Down => false,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::Floor => negative,
+        RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {
+            match (remainder.abs() * 2)
+                .partial_cmp(&denominator.abs())
+                .unwrap()
+            {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match rounding {
+                    RoundingMode::HalfUp => true,
+                    RoundingMode::HalfDown => false,
+                    _ => !quotient.is_even(),
+                },
+            }
+        }
+    };
+
+    if round_away { away } else { quotient }
+}
@@ -0,0 +1,207 @@
+use crate::continued_fraction::{Convergents, sqrt_continued_fraction};
+use crate::number_theory::{extended_gcd, rem_euclid};
+use crate::{BigInt, Matrix};
+
+/// Every solution of a * x + b * y = c as (x + t * x_step, y + t * y_step) for integer t.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LinearDiophantine {
+    pub x: BigInt,
+    pub y: BigInt,
+    pub x_step: BigInt,
+    pub y_step: BigInt,
+}
+
+impl LinearDiophantine {
+    /// The solution for parameter t.
+    pub fn solution<T>(&self, t: T) -> (BigInt, BigInt)
+    where
+        T: Into<BigInt>,
+    {
+        let t = t.into();
+
+        (
+            self.x.clone() + t.clone() * self.x_step.clone(),
+            self.y.clone() + t * self.y_step.clone(),
+        )
+    }
+}
+
+/// Solves a * x + b * y = c, None when there is no solution. The particular solution has the
+/// smallest non-negative x (or y when b is zero) and the step of that variable is positive.
+/// Panics when a and b are both zero.
+pub fn linear_diophantine(a: &BigInt, b: &BigInt, c: &BigInt) -> Option<LinearDiophantine> {
+    assert!(*a != 0 || *b != 0, "a and b are both zero!");
+
+    let (gcd, x, y) = extended_gcd(a, b);
+    if rem_euclid(c, &gcd) != 0 {
+        return None;
+    }
+
+    let scale = c.clone() / gcd.clone();
+    let (mut x, mut y) = (x * scale.clone(), y * scale);
+    let (mut x_step, mut y_step) = (b.clone() / gcd.clone(), -(a.clone() / gcd));
+
+    //the step of the first free variable is positive
+    if x_step < 0 || x_step == 0 && y_step < 0 {
+        x_step = -x_step;
+        y_step = -y_step;
+    }
+
+    let (value, step) = if x_step != 0 {
+        (&x, &x_step)
+    } else {
+        (&y, &y_step)
+    };
+    let t = (rem_euclid(value, step) - value.clone()) / step.clone();
+    x += t.clone() * x_step.clone();
+    y += t * y_step.clone();
+
+    Some(LinearDiophantine {
+        x,
+        y,
+        x_step,
+        y_step,
+    })
+}
+
+/// Solves matrix * x = rhs over the integers through the Hermite normal form. Returns a
+/// particular solution and a basis of the kernel lattice, every solution is the particular
+/// one plus an integer combination of the basis. None when there is no integer solution.
+/// Panics when rhs doesn't have `rows` entries.
+pub fn linear_diophantine_system(
+    matrix: &Matrix<BigInt>,
+    rhs: &[BigInt],
+) -> Option<(Vec<BigInt>, Vec<Vec<BigInt>>)> {
+    assert!(rhs.len() == matrix.rows(), "dimension mismatch!");
+
+    //U * A^T = H, so A * U^T = H^T and x = U^T * y turns the system into H^T * y = rhs
+    let (h, u) = matrix.transpose().hermite_normal_form();
+    let cols = matrix.cols();
+    let rank = (0..h.rows())
+        .take_while(|&row| h.row(row).iter().any(|entry| *entry != 0))
+        .count();
+
+    //H^T is lower triangular in the pivot rows, forward substitution
+    let mut y = vec![BigInt::default(); cols];
+    for i in 0..rank {
+        let pivot = h.row(i).iter().position(|entry| *entry != 0).unwrap();
+        let sum = (0..i).fold(BigInt::default(), |sum, j| {
+            sum + h[(j, pivot)].clone() * y[j].clone()
+        });
+        let remaining = rhs[pivot].clone() - sum;
+
+        if rem_euclid(&remaining, &h[(i, pivot)]) != 0 {
+            return None;
+        }
+        y[i] = remaining / h[(i, pivot)].clone();
+    }
+
+    //the remaining equations have to hold as well
+    for (row, value) in rhs.iter().enumerate() {
+        let sum = (0..rank).fold(BigInt::default(), |sum, j| {
+            sum + h[(j, row)].clone() * y[j].clone()
+        });
+        if sum != *value {
+            return None;
+        }
+    }
+
+    let solution = (0..cols)
+        .map(|col| {
+            (0..rank).fold(BigInt::default(), |sum, j| {
+                sum + u[(j, col)].clone() * y[j].clone()
+            })
+        })
+        .collect();
+    let kernel = (rank..u.rows()).map(|row| u.row(row).to_vec()).collect();
+
+    Some((solution, kernel))
+}
+
+/// Solutions of x^2 - d * y^2 = 1 (or -1 with `Pell::negative`) with x, y > 0 in
+/// increasing order, the fundamental one comes from the continued fraction of sqrt(d).
+#[derive(Clone, Debug)]
+pub struct Pell {
+    d: BigInt,
+    fundamental: (BigInt, BigInt),
+    step: (BigInt, BigInt),
+    current: (BigInt, BigInt),
+}
+
+impl Pell {
+    /// None when d isn't positive or is a perfect square.
+    pub fn new(d: &BigInt) -> Option<Pell> {
+        let (x, y, period) = Pell::last_convergent(d)?;
+
+        //an odd period gives x^2 - d * y^2 = -1, squaring it gives 1
+        let fundamental = if period % 2 == 1 {
+            Pell::compose(d, &(x.clone(), y.clone()), &(x, y))
+        } else {
+            (x, y)
+        };
+
+        Some(Pell {
+            d: d.clone(),
+            fundamental: fundamental.clone(),
+            step: fundamental.clone(),
+            current: fundamental,
+        })
+    }
+
+    /// Solutions of x^2 - d * y^2 = -1, None when there are none.
+    pub fn negative(d: &BigInt) -> Option<Pell> {
+        let (x, y, period) = Pell::last_convergent(d)?;
+
+        //-1 is only reachable with an odd period
+        if period % 2 == 0 {
+            return None;
+        }
+
+        let fundamental = (x, y);
+        Some(Pell {
+            d: d.clone(),
+            step: Pell::compose(d, &fundamental, &fundamental),
+            fundamental: fundamental.clone(),
+            current: fundamental,
+        })
+    }
+
+    /// The smallest solution with x, y > 0.
+    pub fn fundamental(&self) -> (BigInt, BigInt) {
+        self.fundamental.clone()
+    }
+
+    // p and q of the convergent before the end of the first period and the period length
+    fn last_convergent(d: &BigInt) -> Option<(BigInt, BigInt, usize)> {
+        if *d <= 0 {
+            return None;
+        }
+
+        let (root, period) = sqrt_continued_fraction(d);
+        if period.is_empty() {
+            return None;
+        }
+
+        let quotients = std::iter::once(root).chain(period[..period.len() - 1].iter().cloned());
+        let (p, q) = Convergents::new(quotients).last().unwrap();
+
+        Some((p, q, period.len()))
+    }
+
+    // (x1 + y1 sqrt(d)) * (x2 + y2 sqrt(d))
+    fn compose(d: &BigInt, left: &(BigInt, BigInt), right: &(BigInt, BigInt)) -> (BigInt, BigInt) {
+        (
+            left.0.clone() * right.0.clone() + d.clone() * left.1.clone() * right.1.clone(),
+            left.0.clone() * right.1.clone() + left.1.clone() * right.0.clone(),
+        )
+    }
+}
+
+impl Iterator for Pell {
+    type Item = (BigInt, BigInt);
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = Pell::compose(&self.d, &self.current, &self.step);
+
+        Some(std::mem::replace(&mut self.current, next))
+    }
+}
@@ -0,0 +1,542 @@
+use crate::decimal::divide_rounded;
+use crate::float::{bit_length, power_of_two};
+use crate::number_theory::isqrt;
+use crate::{BigFloat, BigInt, RoundingMode};
+
+use num_traits::Pow;
+
+// guard bits of the first attempt, doubled until the result is known to round correctly
+const FIRST_GUARD: u64 = 16;
+// exact ties of pow never separate from a rounding boundary, so pow gives up after this many
+const MAX_POW_ATTEMPTS: u32 = 12;
+
+// Every function below is correctly rounded to nearest (ties to even) with the requested
+// precision. The approximations return a value together with a rigorous error bound and
+// the working precision is raised until both ends of the error interval round the same way.
+impl BigFloat {
+    /// π correctly rounded to `precision` bits.
+    pub fn pi(precision: u64) -> BigFloat {
+        correctly_rounded(precision, |bits| {
+            (pi_fixed(bits), BigInt::from(2), -(bits as i64))
+        })
+    }
+
+    /// Euler's number correctly rounded to `precision` bits.
+    pub fn e(precision: u64) -> BigFloat {
+        correctly_rounded(precision, |bits| {
+            (e_fixed(bits), BigInt::from(2), -(bits as i64))
+        })
+    }
+
+    /// Natural logarithm of 2 correctly rounded to `precision` bits.
+    pub fn ln2(precision: u64) -> BigFloat {
+        correctly_rounded(precision, |bits| {
+            (ln2_fixed(bits), BigInt::from(2), -(bits as i64))
+        })
+    }
+
+    /// e^self correctly rounded to `precision` bits, panics when the exponent of the result
+    /// doesn't fit an i64.
+    pub fn exp(&self, precision: u64) -> BigFloat {
+        //|self| >= 2^64 puts the result beyond 2^(±2^64)
+        if self.top() > 64 {
+            panic!("exponent out of range!");
+        }
+
+        correctly_rounded(precision, |bits| exp_fixed(self, bits))
+    }
+
+    /// Natural logarithm correctly rounded to `precision` bits, panics on non-positive numbers.
+    pub fn ln(&self, precision: u64) -> BigFloat {
+        if !self.mantissa().positive || self.is_zero() {
+            panic!("logarithm of non-positive number!");
+        }
+
+        //ln(1) edgecase
+        if *self.mantissa() == 1 && self.exponent() == 0 {
+            return BigFloat::zero(precision);
+        }
+
+        correctly_rounded(precision, |bits| ln_fixed(self, bits))
+    }
+
+    /// Binary logarithm correctly rounded to `precision` bits, panics on non-positive numbers.
+    pub fn log2(&self, precision: u64) -> BigFloat {
+        if !self.mantissa().positive || self.is_zero() {
+            panic!("logarithm of non-positive number!");
+        }
+
+        //powers of two edgecase
+        if *self.mantissa() == 1 {
+            return BigFloat::round(
+                BigInt::from(self.exponent()),
+                0,
+                precision,
+                RoundingMode::HalfEven,
+            );
+        }
+
+        correctly_rounded(precision, |bits| {
+            let (value, error, exponent) = ln_fixed(self, bits);
+            let scale = (-exponent) as u64;
+
+            let quotient = value.clone() * power_of_two(scale) / ln2_fixed(scale);
+            let error = error * 2 + value.abs() * 3 / power_of_two(scale) + 3;
+
+            (quotient, error, exponent)
+        })
+    }
+
+    /// Sine correctly rounded to `precision` bits.
+    pub fn sin(&self, precision: u64) -> BigFloat {
+        //sin(0) edgecase
+        if self.is_zero() {
+            return BigFloat::zero(precision);
+        }
+
+        correctly_rounded(precision, |bits| sin_cos_fixed(self, bits, false))
+    }
+
+    /// Cosine correctly rounded to `precision` bits.
+    pub fn cos(&self, precision: u64) -> BigFloat {
+        correctly_rounded(precision, |bits| sin_cos_fixed(self, bits, true))
+    }
+
+    /// Arctangent correctly rounded to `precision` bits.
+    pub fn atan(&self, precision: u64) -> BigFloat {
+        //atan(0) edgecase
+        if self.is_zero() {
+            return BigFloat::zero(precision);
+        }
+
+        correctly_rounded(precision, |bits| atan_fixed(self, bits))
+    }
+
+    /// self^exponent rounded to `precision` bits, panics on a negative base with a non-integer
+    /// exponent and on zero to a negative power. Correctly rounded unless the result can't be
+    /// separated from a rounding boundary within MAX_POW_ATTEMPTS widenings, as happens when the
+    /// exact result is a tie between two representable numbers. Then it is off by at most one ulp.
+    pub fn pow(&self, exponent: &BigFloat, precision: u64) -> BigFloat {
+        //x^0 and 1^y edgecase
+        if exponent.is_zero() || (*self.mantissa() == 1 && self.exponent() == 0) {
+            return BigFloat::new(1, 0, precision);
+        }
+
+        //zero base edgecase
+        if self.is_zero() {
+            if !exponent.mantissa().positive {
+                panic!("division by zero!");
+            }
+            return BigFloat::zero(precision);
+        }
+
+        let integer = exponent.exponent() >= 0;
+        if !self.mantissa().positive && !integer {
+            panic!("negative base with non-integer exponent!");
+        }
+
+        //small integer powers are computed exactly and rounded once
+        if integer && exponent.top() < 32 {
+            //top < 32 always fits
+            let power = i64::try_from(&exponent.to_bigint(RoundingMode::Down)).unwrap();
+            let bits = bit_length(self.mantissa()) * power.unsigned_abs();
+
+            if bits <= 4 * precision + 1024 {
+                let exact = BigFloat::round(
+                    self.mantissa().clone().pow(power.unsigned_abs()),
+                    self.exponent() * power.abs(),
+                    bits.max(1),
+                    RoundingMode::HalfEven,
+                );
+
+                if power < 0 {
+                    return BigFloat::from(1).div_rounded(
+                        &exact,
+                        precision,
+                        RoundingMode::HalfEven,
+                    );
+                }
+                return exact.with_precision(precision, RoundingMode::HalfEven);
+            }
+        }
+
+        //odd integer exponents keep the sign of the base
+        let negative = !self.mantissa().positive && exponent.exponent() == 0;
+        let base = self.abs();
+
+        let approximation = |bits| {
+            let (value, error, value_exponent) = pow_fixed(&base, exponent, bits);
+
+            if negative {
+                (-value, error, value_exponent)
+            } else {
+                (value, error, value_exponent)
+            }
+        };
+
+        let mut guard = FIRST_GUARD;
+        let mut attempts = 1;
+
+        loop {
+            let (low, high) = round_interval(precision, guard, &approximation);
+            if low == high || attempts == MAX_POW_ATTEMPTS {
+                return low;
+            }
+
+            guard *= 2;
+            attempts += 1;
+        }
+    }
+}
+
+// Ziv's strategy, the guard bits grow until both ends of the error interval round the same way.
+// Only pow can hit an exact tie, every other result is transcendental or handled as an edgecase,
+// so the loop always ends.
+fn correctly_rounded<F>(precision: u64, approximation: F) -> BigFloat
+where
+    F: Fn(u64) -> (BigInt, BigInt, i64),
+{
+    let mut guard = FIRST_GUARD;
+
+    loop {
+        let (low, high) = round_interval(precision, guard, &approximation);
+        if low == high {
+            return low;
+        }
+
+        guard *= 2;
+    }
+}
+
+// the approximation returns (value, error, exponent) with the exact result inside
+// [(value - error) * 2^exponent, (value + error) * 2^exponent], both ends get rounded
+fn round_interval<F>(precision: u64, guard: u64, approximation: &F) -> (BigFloat, BigFloat)
+where
+    F: Fn(u64) -> (BigInt, BigInt, i64),
+{
+    let (value, error, exponent) = approximation(precision + guard);
+
+    let low = BigFloat::round(
+        value.clone() - error.clone(),
+        exponent,
+        precision,
+        RoundingMode::HalfEven,
+    );
+    let high = BigFloat::round(value + error, exponent, precision, RoundingMode::HalfEven);
+
+    (low, high)
+}
+
+// floor(number * 2^bits)
+fn to_fixed(number: &BigFloat, bits: i64) -> BigInt {
+    let exponent = number.exponent() + bits;
+
+    if exponent >= 0 {
+        return number.mantissa().clone() * power_of_two(exponent as u64);
+    }
+
+    divide_rounded(
+        number.mantissa(),
+        &power_of_two((-exponent) as u64),
+        RoundingMode::Floor,
+    )
+}
+
+// (P, Q, B, T) of sum a(k) / b(k) * p(start)...p(k) / (q(start)...q(k)) for k in start..end,
+// the sum equals T / (B * Q), terms are given as (p, q, a, b)
+pub(crate) fn binary_split<F>(start: u64, end: u64, term: &F) -> (BigInt, BigInt, BigInt, BigInt)
+where
+    F: Fn(u64) -> (BigInt, BigInt, BigInt, BigInt),
+{
+    if end - start == 1 {
+        let (p, q, a, b) = term(start);
+        let t = a * p.clone();
+        return (p, q, b, t);
+    }
+
+    let middle = start + (end - start) / 2;
+    let (left_p, left_q, left_b, left_t) = binary_split(start, middle, term);
+    let (right_p, right_q, right_b, right_t) = binary_split(middle, end, term);
+
+    (
+        left_p.clone() * right_p,
+        left_q * right_q.clone(),
+        left_b.clone() * right_b.clone(),
+        right_b * right_q * left_t + left_b * left_p * right_t,
+    )
+}
+
+// Chudnovsky series, π = 426880 * sqrt(10005) * Q / T
+pub(crate) fn chudnovsky(terms: u64) -> (BigInt, BigInt) {
+    let (_, q, _, t) = binary_split(0, terms, &|k| {
+        if k == 0 {
+            return (
+                BigInt::from(1),
+                BigInt::from(1),
+                BigInt::from(13591409),
+                BigInt::from(1),
+            );
+        }
+
+        let p = BigInt::from((6 * k - 5) * (2 * k - 1)) * (6 * k - 1);
+        let q = BigInt::from(k).pow(3) * 10939058860032000_u64;
+        let a = BigInt::from(13591409) + BigInt::from(545140134) * k;
+
+        (p, q, if k % 2 == 0 { a } else { -a }, BigInt::from(1))
+    });
+
+    (q, t)
+}
+
+// π * 2^bits with an error of at most 2
+fn pi_fixed(bits: u64) -> BigInt {
+    //every term adds more than 47 bits
+    let (q, t) = chudnovsky(bits / 47 + 2);
+    let wide = bits + 8;
+    let root = isqrt(&(BigInt::from(10005) * power_of_two(2 * wide)));
+
+    root * 426880 * q / t / power_of_two(8)
+}
+
+// e * 2^bits with an error of at most 2
+fn e_fixed(bits: u64) -> BigInt {
+    let mut terms = 1;
+    let mut factorial_bits = 0.0;
+    while factorial_bits < (bits + 4) as f64 {
+        terms += 1;
+        factorial_bits += (terms as f64).log2();
+    }
+
+    let (_, q, b, t) = binary_split(0, terms, &|k| {
+        (
+            BigInt::from(1),
+            BigInt::from(k.max(1)),
+            BigInt::from(1),
+            BigInt::from(1),
+        )
+    });
+
+    t * power_of_two(bits) / (b * q)
+}
+
+// ln 2 * 2^bits with an error of at most 2, ln 2 = 2/3 * sum 1 / ((2k + 1) * 9^k)
+fn ln2_fixed(bits: u64) -> BigInt {
+    let (_, q, b, t) = binary_split(0, bits / 3 + 2, &|k| {
+        (
+            BigInt::from(1),
+            BigInt::from(if k == 0 { 1 } else { 9 }),
+            BigInt::from(1),
+            BigInt::from(2 * k + 1),
+        )
+    });
+
+    t * 2 * power_of_two(bits) / (b * q * 3)
+}
+
+// e^x = 2^k * e^r with |r| <= ln 2 / 2, e^r is the Taylor series of r / 2^halvings squared back
+fn exp_fixed(x: &BigFloat, bits: u64) -> (BigInt, BigInt, i64) {
+    let halvings = (bits as f64).sqrt() as u64 / 2 + 1;
+    let bits = bits + 2 * halvings + 16;
+    let one = power_of_two(bits);
+
+    //k does not have to be exact, only close to x / ln 2
+    let rough = 64 + x.top().max(0) as u64;
+    let k = divide_rounded(
+        &to_fixed(x, rough as i64),
+        &ln2_fixed(rough),
+        RoundingMode::HalfEven,
+    );
+
+    let extra = bit_length(&k) + 2;
+    let wide = bits + extra;
+    let reduced =
+        (to_fixed(x, wide as i64) - k.clone() * ln2_fixed(wide)) / power_of_two(extra + halvings);
+
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut terms = 0_u64;
+    loop {
+        terms += 1;
+        term = term * reduced.clone() / one.clone() / terms;
+        if term == 0 {
+            break;
+        }
+        sum += term.clone();
+    }
+
+    for _ in 0..halvings {
+        sum = sum.clone() * sum / one.clone();
+    }
+
+    //every squaring at most quadruples the error
+    let error = BigInt::from(4).pow(halvings) * (5 * terms + 8);
+
+    let exponent = i64::try_from(&k)
+        .ok()
+        .and_then(|k| k.checked_sub(bits as i64))
+        .expect("exponent out of range!");
+
+    (sum, error, exponent)
+}
+
+// ln x = t * ln 2 + 2 * atanh((y - 1) / (y + 1)) with x = y * 2^t and 3/4 <= y < 3/2
+fn ln_fixed(x: &BigFloat, bits: u64) -> (BigInt, BigInt, i64) {
+    let bits = bits + 16;
+    let one = power_of_two(bits);
+
+    let mut t = x.top();
+    let mut y = to_fixed(x, bits as i64 - t);
+    if y.clone() * 4 < one.clone() * 3 {
+        t -= 1;
+        y = to_fixed(x, bits as i64 - t);
+    }
+
+    let z = (y.clone() - one.clone()) * one.clone() / (y + one.clone());
+    let z_squared = z.clone() * z.clone() / one.clone();
+
+    let mut sum = z.clone();
+    let mut power = z;
+    let mut terms = 0_u64;
+    loop {
+        terms += 1;
+        power = power * z_squared.clone() / one.clone();
+        let term = power.clone() / (2 * terms + 1);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+
+    let extra = bit_length(&BigInt::from(t)) + 2;
+    let logarithm = sum * 2 + BigInt::from(t) * ln2_fixed(bits + extra) / power_of_two(extra);
+
+    (logarithm, BigInt::from(4 * terms + 8), -(bits as i64))
+}
+
+// sin and cos of r = x - k * π/2 with |r| <= π/4, the quadrant k picks the series and the sign
+fn sin_cos_fixed(x: &BigFloat, bits: u64, cosine: bool) -> (BigInt, BigInt, i64) {
+    //sin x is close to x for tiny x
+    let bits = bits + 16 + if cosine { 0 } else { (-x.top()).max(0) as u64 };
+    let one = power_of_two(bits);
+
+    //k does not have to be exact, only close to 2x / π
+    let rough = 64 + x.top().max(0) as u64;
+    let k = divide_rounded(
+        &to_fixed(x, rough as i64 + 1),
+        &pi_fixed(rough),
+        RoundingMode::HalfEven,
+    );
+
+    let extra = bit_length(&k) + 2;
+    let wide = bits + extra;
+    let reduced =
+        (to_fixed(x, wide as i64 + 1) - k.clone() * pi_fixed(wide)) / power_of_two(extra + 1);
+    let reduced_squared = reduced.clone() * reduced.clone() / one.clone();
+
+    let quadrant = i64::try_from(&(k % 4)).unwrap().rem_euclid(4);
+    let use_cosine = (quadrant % 2 == 1) != cosine;
+    let negative = if cosine {
+        quadrant == 1 || quadrant == 2
+    } else {
+        quadrant >= 2
+    };
+
+    let mut term = if use_cosine { one.clone() } else { reduced };
+    let mut sum = term.clone();
+    let mut terms = 0_u64;
+    loop {
+        terms += 1;
+        let divisor = if use_cosine {
+            (2 * terms - 1) * (2 * terms)
+        } else {
+            (2 * terms) * (2 * terms + 1)
+        };
+        term = -(term * reduced_squared.clone() / one.clone()) / divisor;
+        if term == 0 {
+            break;
+        }
+        sum += term.clone();
+    }
+
+    if negative {
+        sum = -sum;
+    }
+
+    (sum, BigInt::from(4 * terms + 8), -(bits as i64))
+}
+
+// atan x = ±π/2 - atan(1/x) for |x| > 1, then atan y = 2 * atan(y / (1 + sqrt(1 + y^2))) three
+// times before the Taylor series
+fn atan_fixed(x: &BigFloat, bits: u64) -> (BigInt, BigInt, i64) {
+    const HALVINGS: u64 = 3;
+
+    //atan x is close to x for tiny x
+    let bits = bits + 24 + (-x.top()).max(0) as u64;
+    let one = power_of_two(bits);
+
+    let inverted = x.abs() > BigFloat::from(1);
+    let mut y = to_fixed(x, bits as i64);
+    if inverted {
+        y = one.clone() * one.clone() / y;
+    }
+
+    for _ in 0..HALVINGS {
+        let root = isqrt(&(one.clone() * one.clone() + y.clone() * y.clone()));
+        y = y * one.clone() / (one.clone() + root);
+    }
+
+    let y_squared = y.clone() * y.clone() / one.clone();
+    let mut sum = y.clone();
+    let mut power = y;
+    let mut terms = 0_u64;
+    loop {
+        terms += 1;
+        power = -(power * y_squared.clone() / one.clone());
+        let term = power.clone() / (2 * terms + 1);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+
+    let mut angle = sum * power_of_two(HALVINGS);
+    if inverted {
+        let half_pi = pi_fixed(bits) / 2;
+        angle = if x.mantissa().positive {
+            half_pi - angle
+        } else {
+            -half_pi - angle
+        };
+    }
+
+    (angle, BigInt::from(16 * terms + 100), -(bits as i64))
+}
+
+// x^y = e^(y * ln x) for x > 0, the logarithm gets enough bits for the product to stay accurate
+fn pow_fixed(x: &BigFloat, y: &BigFloat, bits: u64) -> (BigInt, BigInt, i64) {
+    let bits = bits + 16;
+    let wide = bits + (bit_length(y.mantissa()) as i64 + y.exponent()).max(0) as u64 + 16;
+
+    let (logarithm, logarithm_error, logarithm_exponent) = ln_fixed(x, wide);
+    let product = logarithm * y.mantissa().clone();
+    let product_bits = bit_length(&product).max(1);
+    let product_exponent = logarithm_exponent + y.exponent();
+    let product = BigFloat::round(
+        product,
+        product_exponent,
+        product_bits,
+        RoundingMode::HalfEven,
+    );
+
+    let (value, error, exponent) = exp_fixed(&product, bits);
+
+    //an error d of the product changes the result by a factor of at most 1 + 2d
+    let spread = value.abs() * logarithm_error * y.mantissa().abs() * 2;
+    let spread = if product_exponent >= 0 {
+        spread * power_of_two(product_exponent as u64)
+    } else {
+        spread / power_of_two((-product_exponent) as u64)
+    };
+
+    (value, error + spread + 1, exponent)
+}
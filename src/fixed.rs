@@ -0,0 +1,1160 @@
+use crate::{BigInt, BigIntError, BigUint};
+
+use num_traits::Pow;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Binary, Display, LowerHex, UpperHex};
+use std::ops::*;
+use std::str::FromStr;
+
+pub type U256 = Uint<4>;
+pub type U512 = Uint<8>;
+pub type U1024 = Uint<16>;
+pub type I256 = Int<4>;
+pub type I512 = Int<8>;
+pub type I1024 = Int<16>;
+
+// little endian 64 bit limbs, operators wrap like the wrapping_* methods
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+// two's complement in the limbs of a Uint
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Int<const LIMBS: usize> {
+    bits: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    fn default() -> Self {
+        Uint::ZERO
+    }
+}
+
+impl<const LIMBS: usize> Default for Int<LIMBS> {
+    fn default() -> Self {
+        Int::ZERO
+    }
+}
+
+// primitives wider than the type wrap like `as`
+macro_rules! from_primitive_fixed {
+    ($type:ident, $($t:ty),*)=>{
+        $(
+            impl<const LIMBS: usize> From<$t> for $type<LIMBS> {
+                fn from(original_number: $t) -> Self {
+                    let value = original_number as i128;
+                    let fill = if value < 0 { u64::MAX } else { 0 };
+                    let mut limbs = [fill; LIMBS];
+
+                    for (i, limb) in limbs.iter_mut().take(2).enumerate() {
+                        *limb = (value >> (64 * i)) as u64;
+                    }
+
+                    $type::from_limbs(limbs)
+                }
+            }
+
+            impl<const LIMBS: usize> PartialEq<$t> for $type<LIMBS> {
+                fn eq(&self, other: &$t) -> bool {
+                    *self == $type::from(*other)
+                }
+            }
+
+            impl<const LIMBS: usize> PartialOrd<$t> for $type<LIMBS> {
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    Some(self.cmp(&$type::from(*other)))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_fixed!(Uint, u8, u16, u32, u64);
+from_primitive_fixed!(Int, i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl<const LIMBS: usize> From<u128> for Uint<LIMBS> {
+    fn from(original_number: u128) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (i, limb) in limbs.iter_mut().take(2).enumerate() {
+            *limb = (original_number >> (64 * i)) as u64;
+        }
+
+        Uint { limbs }
+    }
+}
+
+impl<const LIMBS: usize> PartialEq<u128> for Uint<LIMBS> {
+    fn eq(&self, other: &u128) -> bool {
+        *self == Uint::from(*other)
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd<u128> for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &u128) -> Option<Ordering> {
+        Some(self.cmp(&Uint::from(*other)))
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BigInt {
+    fn from(number: Uint<LIMBS>) -> Self {
+        number
+            .limbs
+            .iter()
+            .rev()
+            .fold(BigInt::default(), |result, limb| {
+                result * (BigInt::from(u64::MAX) + 1) + *limb
+            })
+    }
+}
+
+impl<const LIMBS: usize> From<Int<LIMBS>> for BigInt {
+    fn from(number: Int<LIMBS>) -> Self {
+        let magnitude = BigInt::from(number.unsigned_abs());
+
+        if number.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigInt> for Uint<LIMBS> {
+    type Error = BigIntError;
+    fn try_from(number: &BigInt) -> Result<Self, Self::Error> {
+        if *number < 0 {
+            return Err(BigIntError::NegativeNumber);
+        }
+
+        Uint::from_magnitude(&number.magnitude).ok_or(BigIntError::LargeNumber)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigInt> for Uint<LIMBS> {
+    type Error = BigIntError;
+    fn try_from(number: BigInt) -> Result<Self, Self::Error> {
+        Uint::try_from(&number)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigInt> for Int<LIMBS> {
+    type Error = BigIntError;
+    fn try_from(number: &BigInt) -> Result<Self, Self::Error> {
+        let out_of_range = || {
+            if number.positive {
+                BigIntError::LargeNumber
+            } else {
+                BigIntError::SmallNumber
+            }
+        };
+        let magnitude =
+            Uint::<LIMBS>::from_magnitude(&number.magnitude).ok_or_else(out_of_range)?;
+
+        //the magnitude of MIN has only the sign bit set
+        let limit = Int::<LIMBS>::MIN.bits;
+        if magnitude > limit || magnitude == limit && number.positive {
+            return Err(out_of_range());
+        }
+
+        let result = Int { bits: magnitude };
+        Ok(if number.positive { result } else { -result })
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigInt> for Int<LIMBS> {
+    type Error = BigIntError;
+    fn try_from(number: BigInt) -> Result<Self, Self::Error> {
+        Int::try_from(&number)
+    }
+}
+
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = BigIntError;
+    fn from_str(string_of_numbers: &str) -> Result<Self, Self::Err> {
+        if string_of_numbers.is_empty()
+            || !string_of_numbers.chars().all(|char| char.is_ascii_digit())
+        {
+            return Err(BigIntError::NaN);
+        }
+
+        string_of_numbers
+            .chars()
+            .try_fold(Uint::ZERO, |result, char| {
+                result
+                    .checked_mul(Uint::from(10u8))
+                    .and_then(|result| result.checked_add(Uint::from(char as u8 - b'0')))
+                    .ok_or(BigIntError::LargeNumber)
+            })
+    }
+}
+
+impl<const LIMBS: usize> FromStr for Int<LIMBS> {
+    type Err = BigIntError;
+    fn from_str(string_of_numbers: &str) -> Result<Self, Self::Err> {
+        let (positive, digits) = match string_of_numbers.strip_prefix('-') {
+            Some(digits) => (false, digits),
+            None => (true, string_of_numbers),
+        };
+        //a second sign fails the digit check of Uint
+        let magnitude: Uint<LIMBS> = digits.parse()?;
+        let limit = Int::<LIMBS>::MIN.bits;
+        if magnitude > limit || magnitude == limit && positive {
+            return Err(BigIntError::LargeNumber);
+        }
+
+        let result = Int { bits: magnitude };
+        Ok(if positive { result } else { -result })
+    }
+}
+
+impl<const LIMBS: usize> Display for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = self.to_decimal_string();
+        if f.sign_plus() {
+            output.insert(0, '+');
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl<const LIMBS: usize> Display for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = self.unsigned_abs().to_decimal_string();
+        if self.is_negative() {
+            output.insert(0, '-');
+        } else if f.sign_plus() {
+            output.insert(0, '+');
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+// negative Int values show their two's complement bits, like primitives
+macro_rules! radix_fixed {
+    ($trait:ident, $bits:expr, $prefix:expr, $digits:expr) => {
+        impl<const LIMBS: usize> $trait for Uint<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut output = self.to_radix_string($bits, $digits);
+                if f.alternate() {
+                    output.insert_str(0, $prefix);
+                }
+
+                BigInt::add_alignment(&mut output, f);
+
+                write!(f, "{output}")
+            }
+        }
+
+        impl<const LIMBS: usize> $trait for Int<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                $trait::fmt(&self.bits, f)
+            }
+        }
+    };
+}
+
+radix_fixed!(Binary, 1, "0b", b"01");
+radix_fixed!(LowerHex, 4, "0x", b"0123456789abcdef");
+radix_fixed!(UpperHex, 4, "0x", b"0123456789ABCDEF");
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Int<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Int<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .is_negative()
+            .cmp(&self.is_negative())
+            .then(self.bits.cmp(&other.bits))
+    }
+}
+
+impl<const LIMBS: usize> Neg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+    fn neg(self) -> Self::Output {
+        self.wrapping_neg()
+    }
+}
+
+impl<const LIMBS: usize> Not for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+    fn not(self) -> Self::Output {
+        Uint {
+            limbs: self.limbs.map(|limb| !limb),
+        }
+    }
+}
+
+impl<const LIMBS: usize> Not for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+    fn not(self) -> Self::Output {
+        Int { bits: !self.bits }
+    }
+}
+
+macro_rules! operator_fixed {
+    ($trait:ident, $function:ident, $assign_trait:ident, $assign_function:ident, $method:ident) => {
+        impl<const LIMBS: usize, T> $trait<T> for Uint<LIMBS>
+        where
+            T: Into<Uint<LIMBS>>,
+        {
+            type Output = Uint<LIMBS>;
+            fn $function(self, rhs: T) -> Self::Output {
+                self.$method(rhs.into())
+            }
+        }
+
+        impl<const LIMBS: usize, T> $trait<T> for Int<LIMBS>
+        where
+            T: Into<Int<LIMBS>>,
+        {
+            type Output = Int<LIMBS>;
+            fn $function(self, rhs: T) -> Self::Output {
+                self.$method(rhs.into())
+            }
+        }
+
+        impl<const LIMBS: usize, T> $assign_trait<T> for Uint<LIMBS>
+        where
+            T: Into<Uint<LIMBS>>,
+        {
+            fn $assign_function(&mut self, rhs: T) {
+                *self = self.$method(rhs.into());
+            }
+        }
+
+        impl<const LIMBS: usize, T> $assign_trait<T> for Int<LIMBS>
+        where
+            T: Into<Int<LIMBS>>,
+        {
+            fn $assign_function(&mut self, rhs: T) {
+                *self = self.$method(rhs.into());
+            }
+        }
+    };
+}
+
+operator_fixed!(Add, add, AddAssign, add_assign, wrapping_add);
+operator_fixed!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
+operator_fixed!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
+operator_fixed!(Div, div, DivAssign, div_assign, wrapping_div);
+operator_fixed!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
+operator_fixed!(BitAnd, bitand, BitAndAssign, bitand_assign, and);
+operator_fixed!(BitOr, bitor, BitOrAssign, bitor_assign, or);
+operator_fixed!(BitXor, bitxor, BitXorAssign, bitxor_assign, xor);
+
+macro_rules! shift_fixed {
+    ($type:ident, $trait:ident, $function:ident, $assign_trait:ident, $assign_function:ident, $method:ident) => {
+        impl<const LIMBS: usize> $trait<u32> for $type<LIMBS> {
+            type Output = $type<LIMBS>;
+            fn $function(self, rhs: u32) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        impl<const LIMBS: usize> $assign_trait<u32> for $type<LIMBS> {
+            fn $assign_function(&mut self, rhs: u32) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+shift_fixed!(Uint, Shl, shl, ShlAssign, shl_assign, wrapping_shl);
+shift_fixed!(Uint, Shr, shr, ShrAssign, shr_assign, wrapping_shr);
+shift_fixed!(Int, Shl, shl, ShlAssign, shl_assign, wrapping_shl);
+shift_fixed!(Int, Shr, shr, ShrAssign, shr_assign, wrapping_shr);
+
+impl<const LIMBS: usize> Pow<u32> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+    fn pow(self, rhs: u32) -> Self::Output {
+        self.wrapping_pow(rhs)
+    }
+}
+
+impl<const LIMBS: usize> Pow<u32> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+    fn pow(self, rhs: u32) -> Self::Output {
+        self.wrapping_pow(rhs)
+    }
+}
+
+// checked_*, saturating_* and wrapping_* in terms of overflowing_*
+macro_rules! checked_wrapping_fixed {
+    ($($checked:ident, $wrapping:ident, $overflowing:ident, $rhs:ty);*) => {
+        $(
+            pub fn $checked(self, rhs: $rhs) -> Option<Self> {
+                match self.$overflowing(rhs) {
+                    (result, false) => Some(result),
+                    _ => None,
+                }
+            }
+
+            pub fn $wrapping(self, rhs: $rhs) -> Self {
+                self.$overflowing(rhs).0
+            }
+        )*
+    };
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    pub const BITS: u32 = 64 * LIMBS as u32;
+    pub const ZERO: Self = Uint { limbs: [0; LIMBS] };
+    pub const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = 1;
+        Uint { limbs }
+    };
+    pub const MAX: Self = Uint {
+        limbs: [u64::MAX; LIMBS],
+    };
+    pub const MIN: Self = Uint::ZERO;
+
+    /// Creates the number from little endian 64 bit limbs.
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Uint { limbs }
+    }
+
+    /// Little endian 64 bit limbs.
+    pub const fn limbs(&self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|limb| *limb == 0)
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.limbs.iter().map(|limb| limb.count_ones()).sum()
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        match self.limbs.iter().rposition(|limb| *limb != 0) {
+            Some(top) => (LIMBS - 1 - top) as u32 * 64 + self.limbs[top].leading_zeros(),
+            None => Uint::<LIMBS>::BITS,
+        }
+    }
+
+    pub fn trailing_zeros(&self) -> u32 {
+        match self.limbs.iter().position(|limb| *limb != 0) {
+            Some(bottom) => bottom as u32 * 64 + self.limbs[bottom].trailing_zeros(),
+            None => Uint::<LIMBS>::BITS,
+        }
+    }
+
+    /// The same bits as a two's complement Int.
+    pub const fn cast_signed(self) -> Int<LIMBS> {
+        Int { bits: self }
+    }
+
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = false;
+
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, first) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (sum, second) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = first || second;
+        }
+
+        (Uint { limbs }, carry)
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = false;
+
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (difference, first) = self.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (difference, second) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = first || second;
+        }
+
+        (Uint { limbs }, borrow)
+    }
+
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+
+        for i in 0..LIMBS {
+            let mut carry = 0;
+            for j in 0..LIMBS - i {
+                let product = self.limbs[i] as u128 * rhs.limbs[j] as u128
+                    + limbs[i + j] as u128
+                    + carry as u128;
+                limbs[i + j] = product as u64;
+                carry = (product >> 64) as u64;
+            }
+
+            //anything that lands at or above LIMBS is lost
+            overflow |= carry != 0
+                || self.limbs[i] != 0 && rhs.limbs[LIMBS - i..].iter().any(|limb| *limb != 0);
+        }
+
+        (Uint { limbs }, overflow)
+    }
+
+    /// Division never overflows, panics on a zero divisor.
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self.div_rem(rhs).0, false)
+    }
+
+    /// Panics on a zero divisor.
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self.div_rem(rhs).1, false)
+    }
+
+    /// 0 - self, overflows for everything but zero.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        Uint::ZERO.overflowing_sub(self)
+    }
+
+    /// Shifts by rhs % BITS, overflows when rhs >= BITS.
+    pub fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        let shift = rhs % Uint::<LIMBS>::BITS;
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let limbs = std::array::from_fn(|i| {
+            if i < limb_shift {
+                return 0;
+            }
+
+            let mut limb = self.limbs[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                limb |= self.limbs[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+            limb
+        });
+
+        (Uint { limbs }, rhs >= Uint::<LIMBS>::BITS)
+    }
+
+    /// Shifts by rhs % BITS, overflows when rhs >= BITS.
+    pub fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        let shift = rhs % Uint::<LIMBS>::BITS;
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let limbs = std::array::from_fn(|i| {
+            if i + limb_shift >= LIMBS {
+                return 0;
+            }
+
+            let mut limb = self.limbs[i + limb_shift] >> bit_shift;
+            if bit_shift > 0 && i + limb_shift + 1 < LIMBS {
+                limb |= self.limbs[i + limb_shift + 1] << (64 - bit_shift);
+            }
+            limb
+        });
+
+        (Uint { limbs }, rhs >= Uint::<LIMBS>::BITS)
+    }
+
+    pub fn overflowing_pow(self, rhs: u32) -> (Self, bool) {
+        let mut result = Uint::ONE;
+        let mut overflow = false;
+
+        //square and multiply from the most significant bit
+        for bit in (0..32 - rhs.leading_zeros()).rev() {
+            let (square, first) = result.overflowing_mul(result);
+            result = square;
+            overflow |= first;
+
+            if rhs >> bit & 1 == 1 {
+                let (product, second) = result.overflowing_mul(self);
+                result = product;
+                overflow |= second;
+            }
+        }
+
+        (result, overflow)
+    }
+
+    checked_wrapping_fixed!(
+        checked_add, wrapping_add, overflowing_add, Self;
+        checked_sub, wrapping_sub, overflowing_sub, Self;
+        checked_mul, wrapping_mul, overflowing_mul, Self;
+        checked_shl, wrapping_shl, overflowing_shl, u32;
+        checked_shr, wrapping_shr, overflowing_shr, u32;
+        checked_pow, wrapping_pow, overflowing_pow, u32
+    );
+
+    /// None on a zero divisor.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(rhs).0)
+    }
+
+    /// None on a zero divisor.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(rhs).1)
+    }
+
+    /// None for everything but zero.
+    pub fn checked_neg(self) -> Option<Self> {
+        self.is_zero().then_some(self)
+    }
+
+    /// Panics on a zero divisor.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Panics on a zero divisor.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Uint::MAX)
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Uint::ZERO)
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Uint::MAX)
+    }
+
+    pub fn saturating_pow(self, rhs: u32) -> Self {
+        self.checked_pow(rhs).unwrap_or(Uint::MAX)
+    }
+
+    /// (quotient, remainder), panics on a zero divisor.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("division by zero!");
+        }
+
+        //single limb divisor edgecase
+        if rhs.limbs[1..].iter().all(|limb| *limb == 0) {
+            let (quotient, remainder) = self.div_rem_limb(rhs.limbs[0]);
+            return (quotient, Uint::from(remainder));
+        }
+
+        //dividend below the divisor edgecase
+        if self < rhs {
+            return (Uint::ZERO, self);
+        }
+
+        //Knuth algorithm D, shifted so the top divisor limb has its highest bit set
+        let length = LIMBS
+            - rhs
+                .limbs
+                .iter()
+                .rev()
+                .take_while(|limb| **limb == 0)
+                .count();
+        let shift = rhs.limbs[length - 1].leading_zeros();
+        let divisor = rhs.wrapping_shl(shift).limbs;
+        let mut remainder = self.wrapping_shl(shift).limbs.to_vec();
+        remainder.push(if shift == 0 {
+            0
+        } else {
+            self.limbs[LIMBS - 1] >> (64 - shift)
+        });
+
+        let top = divisor[length - 1] as u128;
+        let next = divisor[length - 2] as u128;
+        let mut quotient = Uint::ZERO;
+
+        for position in (0..=LIMBS - length).rev() {
+            //estimate from the top two limbs, too large by at most 2
+            let current = (remainder[position + length] as u128) << 64
+                | remainder[position + length - 1] as u128;
+            let mut estimate = current / top;
+            let mut rest = current % top;
+
+            while estimate >> 64 != 0
+                || estimate * next > (rest << 64 | remainder[position + length - 2] as u128)
+            {
+                estimate -= 1;
+                rest += top;
+                if rest >> 64 != 0 {
+                    break;
+                }
+            }
+
+            //remainder -= estimate * divisor at position
+            let mut carry = 0;
+            let mut borrow = false;
+            for (offset, limb) in divisor.iter().take(length).enumerate() {
+                let product = estimate * *limb as u128 + carry;
+                carry = product >> 64;
+                let (difference, first) =
+                    remainder[position + offset].overflowing_sub(product as u64);
+                let (difference, second) = difference.overflowing_sub(borrow as u64);
+                remainder[position + offset] = difference;
+                borrow = first || second;
+            }
+
+            let (difference, first) = remainder[position + length].overflowing_sub(carry as u64);
+            let (difference, second) = difference.overflowing_sub(borrow as u64);
+            remainder[position + length] = difference;
+
+            if first || second {
+                //estimate was one too large, add the divisor back
+                estimate -= 1;
+
+                let mut carry = false;
+                for (offset, limb) in divisor.iter().take(length).enumerate() {
+                    let (sum, first) = remainder[position + offset].overflowing_add(*limb);
+                    let (sum, second) = sum.overflowing_add(carry as u64);
+                    remainder[position + offset] = sum;
+                    carry = first || second;
+                }
+                remainder[position + length] =
+                    remainder[position + length].wrapping_add(carry as u64);
+            }
+
+            quotient.limbs[position] = estimate as u64;
+        }
+
+        //undo the shift of the remainder, which fits in the divisor's limbs
+        let mut limbs = [0; LIMBS];
+        limbs[..length].copy_from_slice(&remainder[..length]);
+
+        (quotient, Uint { limbs }.wrapping_shr(shift))
+    }
+
+    fn div_rem_limb(self, divisor: u64) -> (Self, u64) {
+        let mut limbs = [0; LIMBS];
+        let mut remainder = 0;
+
+        for i in (0..LIMBS).rev() {
+            let current = (remainder as u128) << 64 | self.limbs[i] as u128;
+            limbs[i] = (current / divisor as u128) as u64;
+            remainder = (current % divisor as u128) as u64;
+        }
+
+        (Uint { limbs }, remainder)
+    }
+
+    fn and(self, rhs: Self) -> Self {
+        Uint {
+            limbs: std::array::from_fn(|i| self.limbs[i] & rhs.limbs[i]),
+        }
+    }
+
+    fn or(self, rhs: Self) -> Self {
+        Uint {
+            limbs: std::array::from_fn(|i| self.limbs[i] | rhs.limbs[i]),
+        }
+    }
+
+    fn xor(self, rhs: Self) -> Self {
+        Uint {
+            limbs: std::array::from_fn(|i| self.limbs[i] ^ rhs.limbs[i]),
+        }
+    }
+
+    fn to_decimal_string(self) -> String {
+        //zero edgecase
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        //19 decimal digits at a time
+        let mut chunks = vec![];
+        let mut remaining = self;
+        while !remaining.is_zero() {
+            let (quotient, chunk) = remaining.div_rem_limb(10_000_000_000_000_000_000);
+            chunks.push(chunk);
+            remaining = quotient;
+        }
+
+        let mut output = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            output.push_str(&format!("{chunk:019}"));
+        }
+
+        output
+    }
+
+    fn to_radix_string(self, bits: u32, digits: &[u8]) -> String {
+        let length = (Uint::<LIMBS>::BITS - self.leading_zeros())
+            .div_ceil(bits)
+            .max(1);
+
+        (0..length)
+            .rev()
+            .map(|position| {
+                let shifted = self.wrapping_shr(position * bits);
+                char::from(digits[(shifted.limbs[0] & ((1 << bits) - 1)) as usize])
+            })
+            .collect()
+    }
+
+    // None when the magnitude needs more than BITS bits
+    fn from_magnitude(magnitude: &BigUint) -> Option<Self> {
+        let binary = magnitude.to_binary();
+        let significant = &binary[binary.iter().position(|bit| *bit).unwrap_or(binary.len())..];
+        if significant.len() > Uint::<LIMBS>::BITS as usize {
+            return None;
+        }
+
+        let mut limbs = [0; LIMBS];
+        for (position, bit) in significant.iter().rev().enumerate() {
+            limbs[position / 64] |= (*bit as u64) << (position % 64);
+        }
+
+        Some(Uint { limbs })
+    }
+}
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    pub const BITS: u32 = Uint::<LIMBS>::BITS;
+    pub const ZERO: Self = Int { bits: Uint::ZERO };
+    pub const ONE: Self = Int { bits: Uint::ONE };
+    pub const MIN: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[LIMBS - 1] = 1 << 63;
+        Int {
+            bits: Uint { limbs },
+        }
+    };
+    pub const MAX: Self = {
+        let mut limbs = [u64::MAX; LIMBS];
+        limbs[LIMBS - 1] = u64::MAX >> 1;
+        Int {
+            bits: Uint { limbs },
+        }
+    };
+
+    /// Creates the number from little endian 64 bit limbs in two's complement.
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Int {
+            bits: Uint { limbs },
+        }
+    }
+
+    /// Little endian 64 bit limbs in two's complement.
+    pub const fn limbs(&self) -> [u64; LIMBS] {
+        self.bits.limbs
+    }
+
+    /// The same bits as a Uint.
+    pub const fn cast_unsigned(self) -> Uint<LIMBS> {
+        self.bits
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.bits.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.bits.limbs[LIMBS - 1] >> 63 == 1
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// -1, 0 or 1.
+    pub fn signum(&self) -> Self {
+        if self.is_negative() {
+            -Int::ONE
+        } else if self.is_zero() {
+            Int::ZERO
+        } else {
+            Int::ONE
+        }
+    }
+
+    /// |self| as a Uint, MIN included.
+    pub fn unsigned_abs(self) -> Uint<LIMBS> {
+        if self.is_negative() {
+            self.bits.wrapping_neg()
+        } else {
+            self.bits
+        }
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.bits.count_ones()
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        self.bits.leading_zeros()
+    }
+
+    pub fn trailing_zeros(&self) -> u32 {
+        self.bits.trailing_zeros()
+    }
+
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = Int {
+            bits: self.bits.wrapping_add(rhs.bits),
+        };
+
+        //both operands have the same sign and the result has the other one
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+
+        (result, overflow)
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = Int {
+            bits: self.bits.wrapping_sub(rhs.bits),
+        };
+
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+
+        (result, overflow)
+    }
+
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        //the low bits of the product don't depend on the signs
+        let result = Int {
+            bits: self.bits.wrapping_mul(rhs.bits),
+        };
+
+        let (magnitude, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let limit = Int::<LIMBS>::MIN.bits;
+        let negative = self.is_negative() != rhs.is_negative();
+        let overflow = overflow || magnitude > limit || magnitude == limit && !negative;
+
+        (result, overflow)
+    }
+
+    /// Truncating division, MIN / -1 overflows. Panics on a zero divisor.
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        if self == Int::MIN && rhs == -1 {
+            return (Int::MIN, true);
+        }
+
+        (self.div_rem(rhs).0, false)
+    }
+
+    /// The remainder has the sign of the dividend, MIN % -1 overflows. Panics on a zero divisor.
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        if self == Int::MIN && rhs == -1 {
+            return (Int::ZERO, true);
+        }
+
+        (self.div_rem(rhs).1, false)
+    }
+
+    /// -MIN overflows.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (
+            Int {
+                bits: self.bits.wrapping_neg(),
+            },
+            self == Int::MIN,
+        )
+    }
+
+    /// Shifts by rhs % BITS, overflows when rhs >= BITS.
+    pub fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        let (bits, overflow) = self.bits.overflowing_shl(rhs);
+
+        (Int { bits }, overflow)
+    }
+
+    /// Arithmetic shift by rhs % BITS, overflows when rhs >= BITS.
+    pub fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        let (bits, overflow) = self.bits.overflowing_shr(rhs);
+        let shift = rhs % Int::<LIMBS>::BITS;
+
+        //the sign bit fills the vacated bits
+        if self.is_negative() && shift > 0 {
+            let fill = Uint::MAX.wrapping_shl(Int::<LIMBS>::BITS - shift);
+            return (
+                Int {
+                    bits: bits.or(fill),
+                },
+                overflow,
+            );
+        }
+
+        (Int { bits }, overflow)
+    }
+
+    pub fn overflowing_pow(self, rhs: u32) -> (Self, bool) {
+        let mut result = Int::ONE;
+        let mut overflow = false;
+
+        for bit in (0..32 - rhs.leading_zeros()).rev() {
+            let (square, first) = result.overflowing_mul(result);
+            result = square;
+            overflow |= first;
+
+            if rhs >> bit & 1 == 1 {
+                let (product, second) = result.overflowing_mul(self);
+                result = product;
+                overflow |= second;
+            }
+        }
+
+        (result, overflow)
+    }
+
+    checked_wrapping_fixed!(
+        checked_add, wrapping_add, overflowing_add, Self;
+        checked_sub, wrapping_sub, overflowing_sub, Self;
+        checked_mul, wrapping_mul, overflowing_mul, Self;
+        checked_shl, wrapping_shl, overflowing_shl, u32;
+        checked_shr, wrapping_shr, overflowing_shr, u32;
+        checked_pow, wrapping_pow, overflowing_pow, u32
+    );
+
+    /// None on a zero divisor or MIN / -1.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        match self.overflowing_div(rhs) {
+            (result, false) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// None on a zero divisor or MIN % -1.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        match self.overflowing_rem(rhs) {
+            (result, false) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// None for MIN.
+    pub fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (result, false) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// MIN / -1 is MIN, panics on a zero divisor.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// MIN % -1 is zero, panics on a zero divisor.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(if rhs.is_negative() {
+            Int::MIN
+        } else {
+            Int::MAX
+        })
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(if rhs.is_negative() {
+            Int::MAX
+        } else {
+            Int::MIN
+        })
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or(if self.is_negative() == rhs.is_negative() {
+                Int::MAX
+            } else {
+                Int::MIN
+            })
+    }
+
+    /// MIN / -1 is MAX, panics on a zero divisor.
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        match self.overflowing_div(rhs) {
+            (_, true) => Int::MAX,
+            (result, false) => result,
+        }
+    }
+
+    pub fn saturating_neg(self) -> Self {
+        self.checked_neg().unwrap_or(Int::MAX)
+    }
+
+    pub fn saturating_pow(self, rhs: u32) -> Self {
+        self.checked_pow(rhs)
+            .unwrap_or(if self.is_negative() && rhs % 2 == 1 {
+                Int::MIN
+            } else {
+                Int::MAX
+            })
+    }
+
+    /// Truncating (quotient, remainder), the remainder has the sign of the dividend.
+    /// MIN / -1 wraps, panics on a zero divisor.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (quotient, remainder) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let (quotient, remainder) = (quotient.cast_signed(), remainder.cast_signed());
+
+        (
+            if self.is_negative() != rhs.is_negative() {
+                -quotient
+            } else {
+                quotient
+            },
+            if self.is_negative() {
+                -remainder
+            } else {
+                remainder
+            },
+        )
+    }
+
+    fn and(self, rhs: Self) -> Self {
+        Int {
+            bits: self.bits.and(rhs.bits),
+        }
+    }
+
+    fn or(self, rhs: Self) -> Self {
+        Int {
+            bits: self.bits.or(rhs.bits),
+        }
+    }
+
+    fn xor(self, rhs: Self) -> Self {
+        Int {
+            bits: self.bits.xor(rhs.bits),
+        }
+    }
+}
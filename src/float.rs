@@ -0,0 +1,712 @@
+use crate::decimal::{divide_rounded, shift_digits};
+use crate::number_theory::isqrt;
+use crate::{BigInt, BigIntError, RoundingMode};
+
+use num_traits::Pow;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::*;
+
+const F64_PRECISION: u64 = 53;
+const F32_PRECISION: u64 = 24;
+
+// value is mantissa * 2^exponent, the mantissa is odd and fits into precision bits
+#[derive(Clone, Debug)]
+pub struct BigFloat {
+    mantissa: BigInt,
+    exponent: i64,
+    precision: u64,
+}
+
+impl Default for BigFloat {
+    fn default() -> Self {
+        BigFloat {
+            mantissa: BigInt::default(),
+            exponent: 0,
+            precision: F64_PRECISION,
+        }
+    }
+}
+
+impl From<BigInt> for BigFloat {
+    fn from(number: BigInt) -> Self {
+        let precision = bit_length(&number).max(F64_PRECISION);
+
+        BigFloat::round(number, 0, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl From<&BigInt> for BigFloat {
+    fn from(number: &BigInt) -> Self {
+        BigFloat::from(number.clone())
+    }
+}
+
+macro_rules! from_primitive_float {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for BigFloat{
+                fn from(original_number: $t) -> Self {
+                    BigFloat::from(BigInt::from(original_number))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_float!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl TryFrom<f64> for BigFloat {
+    type Error = BigIntError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            return Err(BigIntError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(BigIntError::LargeNumber);
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+
+        //subnormal numbers have no implicit leading bit
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        let mut mantissa = BigInt::from(mantissa);
+        if value.is_sign_negative() {
+            mantissa = -mantissa;
+        }
+
+        Ok(BigFloat::round(
+            mantissa,
+            exponent,
+            F64_PRECISION,
+            RoundingMode::HalfEven,
+        ))
+    }
+}
+
+impl TryFrom<f64> for BigInt {
+    type Error = BigIntError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let float = BigInt::finite_float(value)?;
+
+        //a normalized mantissa is odd, so a negative exponent means a fraction
+        if float.exponent < 0 {
+            return Err(BigIntError::FractionalNumber);
+        }
+
+        Ok(float.to_bigint(RoundingMode::Down))
+    }
+}
+
+impl TryFrom<f32> for BigInt {
+    type Error = BigIntError;
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        BigInt::try_from(value as f64)
+    }
+}
+
+macro_rules! compare_with_float {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<$t> for BigInt {
+                fn eq(&self, other: &$t) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl PartialOrd<$t> for BigInt {
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    let other = *other as f64;
+
+                    if other.is_nan() {
+                        return None;
+                    }
+                    if other.is_infinite() {
+                        return Some(if other > 0.0 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        });
+                    }
+
+                    //both sides are exact, so is the comparison
+                    Some(BigFloat::from(self).cmp(&BigFloat::try_from(other).unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+compare_with_float!(f32, f64);
+
+impl BigInt {
+    /// Nearest `f64`, ties to even, out of range values become infinite.
+    pub fn to_f64(&self) -> f64 {
+        self.to_float(F64_PRECISION, 1024)
+    }
+
+    /// Nearest `f32`, ties to even, out of range values become infinite.
+    pub fn to_f32(&self) -> f32 {
+        self.to_float(F32_PRECISION, 128) as f32
+    }
+
+    /// Drops the fractional part, errors on NaN and infinities.
+    pub fn from_f64_truncated(value: f64) -> Result<BigInt, BigIntError> {
+        BigInt::from_f64_rounded(value, RoundingMode::Down)
+    }
+
+    /// Rounds to an integer, errors on NaN and infinities.
+    pub fn from_f64_rounded(value: f64, rounding: RoundingMode) -> Result<BigInt, BigIntError> {
+        Ok(BigInt::finite_float(value)?.to_bigint(rounding))
+    }
+
+    fn finite_float(value: f64) -> Result<BigFloat, BigIntError> {
+        if value == f64::NEG_INFINITY {
+            return Err(BigIntError::SmallNumber);
+        }
+
+        BigFloat::try_from(value)
+    }
+
+    // rounds to precision bits, infinite when the highest bit reaches 2^max_top
+    fn to_float(&self, precision: u64, max_top: i64) -> f64 {
+        //zero edgecase
+        if *self == 0 {
+            return 0.0;
+        }
+
+        let rounded = BigFloat::round(self.abs(), 0, precision, RoundingMode::HalfEven);
+        let magnitude = if rounded.top() > max_top {
+            f64::INFINITY
+        } else {
+            //the mantissa fits and the exponent is a power of two, so this is exact
+            to_u64(&rounded.mantissa) as f64
+                * f64::from_bits((rounded.exponent as u64 + 1023) << 52)
+        };
+
+        if self.positive { magnitude } else { -magnitude }
+    }
+}
+
+impl Display for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        if !self.mantissa.positive {
+            output.push('-');
+        }
+        if f.sign_plus() && self.mantissa.positive {
+            output.push('+');
+        }
+
+        //zero edgecase
+        if self.mantissa == 0 {
+            output.push('0');
+        } else {
+            //precision of the formatter is the number of significant digits
+            let (digits, exponent) = match f.precision() {
+                Some(digits) => self.to_decimal(digits.max(1)),
+                None => {
+                    let digits = (self.precision * 30103 / 100000).max(1) as usize;
+                    let (digits, exponent) = self.to_decimal(digits);
+                    (digits.trim_end_matches('0').to_string(), exponent)
+                }
+            };
+
+            if !(-6..21).contains(&exponent) {
+                output.push_str(&digits[..1]);
+                if digits.len() > 1 {
+                    output.push('.');
+                    output.push_str(&digits[1..]);
+                }
+                output.push_str(&format!("e{exponent}"));
+            } else if exponent < 0 {
+                output.push_str("0.");
+                output.push_str(&"0".repeat((-exponent - 1) as usize));
+                output.push_str(&digits);
+            } else {
+                let integer_digits = exponent as usize + 1;
+                if digits.len() <= integer_digits {
+                    output.push_str(&digits);
+                    output.push_str(&"0".repeat(integer_digits - digits.len()));
+                } else {
+                    output.push_str(&digits[..integer_digits]);
+                    output.push('.');
+                    output.push_str(&digits[integer_digits..]);
+                }
+            }
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl PartialEq for BigFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.mantissa == other.mantissa && self.exponent == other.exponent
+    }
+}
+
+impl Eq for BigFloat {}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = |number: &BigFloat| match number.mantissa.partial_cmp(&0).unwrap() {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        let (left_sign, right_sign) = (sign(self), sign(other));
+        if left_sign != right_sign || left_sign == 0 {
+            return left_sign.cmp(&right_sign);
+        }
+
+        //the position of the highest bit decides unless it is the same
+        let ordering = self.top().cmp(&other.top());
+        if ordering != Ordering::Equal {
+            return if left_sign > 0 {
+                ordering
+            } else {
+                ordering.reverse()
+            };
+        }
+
+        let exponent = self.exponent.min(other.exponent);
+        let left = self.mantissa.clone() * power_of_two((self.exponent - exponent) as u64);
+        let right = other.mantissa.clone() * power_of_two((other.exponent - exponent) as u64);
+
+        left.partial_cmp(&right).unwrap()
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+    fn neg(self) -> Self::Output {
+        BigFloat {
+            mantissa: -self.mantissa,
+            exponent: self.exponent,
+            precision: self.precision,
+        }
+    }
+}
+
+impl<T> Add<T> for BigFloat
+where
+    T: Into<BigFloat>,
+{
+    type Output = BigFloat;
+    fn add(self, right: T) -> Self::Output {
+        let right = right.into();
+        let precision = self.precision.max(right.precision);
+
+        self.add_rounded(&right, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl<T> Sub<T> for BigFloat
+where
+    T: Into<BigFloat>,
+{
+    type Output = BigFloat;
+    fn sub(self, right: T) -> Self::Output {
+        let right = right.into();
+        let precision = self.precision.max(right.precision);
+
+        self.sub_rounded(&right, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl<T> Mul<T> for BigFloat
+where
+    T: Into<BigFloat>,
+{
+    type Output = BigFloat;
+    fn mul(self, right: T) -> Self::Output {
+        let right = right.into();
+        let precision = self.precision.max(right.precision);
+
+        self.mul_rounded(&right, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl<T> Div<T> for BigFloat
+where
+    T: Into<BigFloat>,
+{
+    type Output = BigFloat;
+    fn div(self, right: T) -> Self::Output {
+        let right = right.into();
+        let precision = self.precision.max(right.precision);
+
+        self.div_rounded(&right, precision, RoundingMode::HalfEven)
+    }
+}
+
+assign_trait!(BigFloat, AddAssign, add_assign, add);
+assign_trait!(BigFloat, SubAssign, sub_assign, sub);
+assign_trait!(BigFloat, MulAssign, mul_assign, mul);
+assign_trait!(BigFloat, DivAssign, div_assign, div);
+
+impl BigFloat {
+    /// Creates `mantissa * 2^exponent` rounded to nearest even with `precision` bits.
+    pub fn new<T>(mantissa: T, exponent: i64, precision: u64) -> BigFloat
+    where
+        T: Into<BigInt>,
+    {
+        BigFloat::round(mantissa.into(), exponent, precision, RoundingMode::HalfEven)
+    }
+
+    /// Creates zero with `precision` bits.
+    pub fn zero(precision: u64) -> BigFloat {
+        BigFloat::round(BigInt::default(), 0, precision, RoundingMode::HalfEven)
+    }
+
+    pub fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    pub fn precision(&self) -> u64 {
+        self.precision
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub fn abs(&self) -> BigFloat {
+        BigFloat {
+            mantissa: self.mantissa.abs(),
+            exponent: self.exponent,
+            precision: self.precision,
+        }
+    }
+
+    /// Rounds the value to `precision` bits.
+    pub fn with_precision(&self, precision: u64, rounding: RoundingMode) -> BigFloat {
+        BigFloat::round(self.mantissa.clone(), self.exponent, precision, rounding)
+    }
+
+    pub fn add_rounded(
+        &self,
+        other: &BigFloat,
+        precision: u64,
+        rounding: RoundingMode,
+    ) -> BigFloat {
+        //zero edgecases
+        if self.is_zero() {
+            return other.with_precision(precision, rounding);
+        }
+        if other.is_zero() {
+            return self.with_precision(precision, rounding);
+        }
+
+        let (larger, mut smaller) = if self.top() >= other.top() {
+            (self.clone(), other.clone())
+        } else {
+            (other.clone(), self.clone())
+        };
+
+        //an operand far below the rounding position only matters as a sticky bit
+        let sticky = larger.exponent.min(larger.top() - precision as i64 - 2);
+        if smaller.top() < sticky {
+            smaller = BigFloat {
+                mantissa: if smaller.mantissa.positive {
+                    BigInt::from(1)
+                } else {
+                    BigInt::from(-1)
+                },
+                exponent: sticky - 1,
+                precision: 1,
+            };
+        }
+
+        let exponent = larger.exponent.min(smaller.exponent);
+        let mantissa = larger.mantissa * power_of_two((larger.exponent - exponent) as u64)
+            + smaller.mantissa * power_of_two((smaller.exponent - exponent) as u64);
+
+        BigFloat::round(mantissa, exponent, precision, rounding)
+    }
+
+    pub fn sub_rounded(
+        &self,
+        other: &BigFloat,
+        precision: u64,
+        rounding: RoundingMode,
+    ) -> BigFloat {
+        self.add_rounded(&-other.clone(), precision, rounding)
+    }
+
+    pub fn mul_rounded(
+        &self,
+        other: &BigFloat,
+        precision: u64,
+        rounding: RoundingMode,
+    ) -> BigFloat {
+        BigFloat::round(
+            self.mantissa.clone() * other.mantissa.clone(),
+            self.exponent + other.exponent,
+            precision,
+            rounding,
+        )
+    }
+
+    /// Correctly rounded division, panics on a zero divisor.
+    pub fn div_rounded(
+        &self,
+        other: &BigFloat,
+        precision: u64,
+        rounding: RoundingMode,
+    ) -> BigFloat {
+        if other.is_zero() {
+            panic!("division by zero!");
+        }
+
+        //zero edgecase
+        if self.is_zero() {
+            return BigFloat::zero(precision);
+        }
+
+        //at least precision + 2 bits of quotient, the rest is kept as a sticky bit
+        let shift = (precision as i64 + 3 + bit_length(&other.mantissa) as i64
+            - bit_length(&self.mantissa) as i64)
+            .max(0) as u64;
+
+        let (mut quotient, remainder) = BigInt::divide_with_remainder(
+            self.mantissa.abs() * power_of_two(shift),
+            other.mantissa.abs(),
+        );
+        let mut exponent = self.exponent - other.exponent - shift as i64;
+
+        if remainder != 0 {
+            quotient = quotient * 2 + 1;
+            exponent -= 1;
+        }
+        if self.mantissa.positive != other.mantissa.positive {
+            quotient = -quotient;
+        }
+
+        BigFloat::round(quotient, exponent, precision, rounding)
+    }
+
+    /// Correctly rounded square root with the precision of `self`, panics on negative numbers.
+    pub fn sqrt(&self) -> BigFloat {
+        self.sqrt_rounded(self.precision, RoundingMode::HalfEven)
+    }
+
+    /// Correctly rounded square root, panics on negative numbers.
+    pub fn sqrt_rounded(&self, precision: u64, rounding: RoundingMode) -> BigFloat {
+        if !self.mantissa.positive {
+            panic!("square root of negative number!");
+        }
+
+        //zero edgecase
+        if self.is_zero() {
+            return BigFloat::zero(precision);
+        }
+
+        let (mut mantissa, mut exponent) = (self.mantissa.clone(), self.exponent);
+        if exponent.rem_euclid(2) == 1 {
+            mantissa *= 2;
+            exponent -= 1;
+        }
+
+        //at least precision + 2 bits of root, the rest is kept as a sticky bit
+        let shift = (precision as i64 + 3 - bit_length(&mantissa) as i64 / 2).max(0) as u64;
+        let scaled = mantissa * power_of_two(2 * shift);
+
+        let mut root = isqrt(&scaled);
+        let mut exponent = exponent / 2 - shift as i64;
+
+        if root.clone() * root.clone() != scaled {
+            root = root * 2 + 1;
+            exponent -= 1;
+        }
+
+        BigFloat::round(root, exponent, precision, rounding)
+    }
+
+    /// Rounds to an integer.
+    pub fn to_bigint(&self, rounding: RoundingMode) -> BigInt {
+        if self.exponent >= 0 {
+            return self.mantissa.clone() * power_of_two(self.exponent as u64);
+        }
+
+        divide_rounded(
+            &self.mantissa,
+            &power_of_two((-self.exponent) as u64),
+            rounding,
+        )
+    }
+
+    /// Nearest `f64`, ties to even, out of range values become infinite.
+    pub fn to_f64(&self) -> f64 {
+        //zero edgecase
+        if self.is_zero() {
+            return 0.0;
+        }
+
+        let sign = if self.mantissa.positive {
+            0
+        } else {
+            1_u64 << 63
+        };
+
+        //subnormal numbers are rounded to a multiple of 2^-1074
+        if self.top() < -1021 {
+            let mantissa = if self.exponent >= -1074 {
+                self.mantissa.abs() * power_of_two((self.exponent + 1074) as u64)
+            } else {
+                divide_rounded(
+                    &self.mantissa.abs(),
+                    &power_of_two((-1074 - self.exponent) as u64),
+                    RoundingMode::HalfEven,
+                )
+            };
+
+            return f64::from_bits(sign | to_u64(&mantissa));
+        }
+
+        let rounded = self.with_precision(F64_PRECISION, RoundingMode::HalfEven);
+        if rounded.top() > 1024 {
+            return if self.mantissa.positive {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+        }
+
+        //shift the mantissa to exactly 53 bits and drop the implicit leading bit
+        let shift = F64_PRECISION - bit_length(&rounded.mantissa);
+        let mantissa = to_u64(&rounded.mantissa.abs()) << shift;
+        let biased_exponent = (rounded.exponent - shift as i64 + 1075) as u64;
+
+        f64::from_bits(sign | (biased_exponent << 52) | (mantissa & ((1 << 52) - 1)))
+    }
+
+    // rounds mantissa * 2^exponent to precision bits and strips trailing zero bits
+    pub(crate) fn round(
+        mantissa: BigInt,
+        exponent: i64,
+        precision: u64,
+        rounding: RoundingMode,
+    ) -> BigFloat {
+        if precision == 0 {
+            panic!("precision must be positive!");
+        }
+
+        //zero edgecase
+        if mantissa == 0 {
+            return BigFloat {
+                mantissa,
+                exponent: 0,
+                precision,
+            };
+        }
+
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+
+        let bits = bit_length(&mantissa);
+        if bits > precision {
+            let shift = bits - precision;
+            mantissa = divide_rounded(&mantissa, &power_of_two(shift), rounding);
+            exponent += shift as i64;
+        }
+
+        let trailing_zeros = mantissa
+            .magnitude
+            .to_binary()
+            .iter()
+            .rev()
+            .take_while(|&&bit| !bit)
+            .count();
+        if trailing_zeros > 0 {
+            mantissa /= power_of_two(trailing_zeros as u64);
+            exponent += trailing_zeros as i64;
+        }
+
+        BigFloat {
+            mantissa,
+            exponent,
+            precision,
+        }
+    }
+
+    // exponent of the bit just above the highest set bit
+    pub(crate) fn top(&self) -> i64 {
+        self.exponent + bit_length(&self.mantissa) as i64
+    }
+
+    // significant decimal digits rounded to nearest even and the decimal exponent of the first one
+    fn to_decimal(&self, digits: usize) -> (String, i64) {
+        let numerator = self.mantissa.abs() * power_of_two(self.exponent.max(0) as u64);
+        let denominator = power_of_two((-self.exponent).max(0) as u64);
+
+        let mut exponent =
+            numerator.magnitude.numbers.len() as i64 - denominator.magnitude.numbers.len() as i64;
+
+        loop {
+            let shift = digits as i64 - 1 - exponent;
+            let scaled = if shift >= 0 {
+                divide_rounded(
+                    &shift_digits(&numerator, shift as u64),
+                    &denominator,
+                    RoundingMode::HalfEven,
+                )
+            } else {
+                divide_rounded(
+                    &numerator,
+                    &shift_digits(&denominator, (-shift) as u64),
+                    RoundingMode::HalfEven,
+                )
+            };
+
+            match scaled.magnitude.numbers.len().cmp(&digits) {
+                Ordering::Greater => exponent += 1,
+                Ordering::Less => exponent -= 1,
+                Ordering::Equal => return (scaled.to_string(), exponent),
+            }
+        }
+    }
+}
+
+pub(crate) fn bit_length(number: &BigInt) -> u64 {
+    //zero edgecase
+    if *number == 0 {
+        return 0;
+    }
+
+    number.magnitude.to_binary().len() as u64
+}
+
+pub(crate) fn power_of_two(exponent: u64) -> BigInt {
+    BigInt::from(2).pow(exponent)
+}
+
+fn to_u64(number: &BigInt) -> u64 {
+    number
+        .magnitude
+        .numbers
+        .iter()
+        .fold(0, |result, &digit| result * 10 + digit as u64)
+}
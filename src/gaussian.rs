@@ -0,0 +1,344 @@
+use crate::decimal::divide_rounded;
+use crate::number_theory::{factorize, mod_pow, rem_euclid};
+use crate::{BigInt, RoundingMode};
+
+use num_traits::Pow;
+
+use std::fmt::{self, Display};
+use std::ops::*;
+
+// re + im * i
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct GaussianInt {
+    pub re: BigInt,
+    pub im: BigInt,
+}
+
+impl From<BigInt> for GaussianInt {
+    fn from(re: BigInt) -> Self {
+        GaussianInt {
+            re,
+            im: BigInt::default(),
+        }
+    }
+}
+
+impl From<&BigInt> for GaussianInt {
+    fn from(re: &BigInt) -> Self {
+        GaussianInt::from(re.clone())
+    }
+}
+
+macro_rules! from_primitive_gaussian {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for GaussianInt{
+                fn from(original_number: $t) -> Self {
+                    GaussianInt::from(BigInt::from(original_number))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_gaussian!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl Display for GaussianInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        let imaginary = if self.im.abs() == 1 {
+            "i".to_string()
+        } else {
+            format!("{}i", self.im.abs())
+        };
+
+        if self.im == 0 {
+            output.push_str(&self.re.to_string());
+        } else if self.re == 0 {
+            if !self.im.positive {
+                output.push('-');
+            }
+            output.push_str(&imaginary);
+        } else {
+            output.push_str(&self.re.to_string());
+            output.push_str(if self.im.positive { " + " } else { " - " });
+            output.push_str(&imaginary);
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl Neg for GaussianInt {
+    type Output = GaussianInt;
+    fn neg(self) -> Self::Output {
+        GaussianInt {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<T> Add<T> for GaussianInt
+where
+    T: Into<GaussianInt>,
+{
+    type Output = GaussianInt;
+    fn add(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        GaussianInt {
+            re: self.re + right.re,
+            im: self.im + right.im,
+        }
+    }
+}
+
+impl<T> Sub<T> for GaussianInt
+where
+    T: Into<GaussianInt>,
+{
+    type Output = GaussianInt;
+    fn sub(self, right: T) -> Self::Output {
+        self + (-right.into())
+    }
+}
+
+impl<T> Mul<T> for GaussianInt
+where
+    T: Into<GaussianInt>,
+{
+    type Output = GaussianInt;
+    fn mul(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        GaussianInt {
+            re: self.re.clone() * right.re.clone() - self.im.clone() * right.im.clone(),
+            im: self.re * right.im + self.im * right.re,
+        }
+    }
+}
+
+impl<T> Div<T> for GaussianInt
+where
+    T: Into<GaussianInt>,
+{
+    type Output = GaussianInt;
+    fn div(self, right: T) -> Self::Output {
+        self.div_rem(&right.into()).0
+    }
+}
+
+impl<T> Rem<T> for GaussianInt
+where
+    T: Into<GaussianInt>,
+{
+    type Output = GaussianInt;
+    fn rem(self, right: T) -> Self::Output {
+        self.div_rem(&right.into()).1
+    }
+}
+
+// panics on a negative exponent
+impl<T> Pow<T> for GaussianInt
+where
+    T: Into<BigInt>,
+{
+    type Output = GaussianInt;
+    fn pow(self, right: T) -> Self::Output {
+        let right = right.into();
+        assert!(right >= 0, "negative exponent!");
+
+        let mut result = GaussianInt::from(1);
+
+        //square and multiply from the most significant bit
+        for bit in right.to_binary().1 {
+            result = result.clone() * result;
+            if bit {
+                result *= self.clone();
+            }
+        }
+
+        result
+    }
+}
+
+assign_trait!(GaussianInt, AddAssign, add_assign, add);
+assign_trait!(GaussianInt, SubAssign, sub_assign, sub);
+assign_trait!(GaussianInt, MulAssign, mul_assign, mul);
+assign_trait!(GaussianInt, DivAssign, div_assign, div);
+assign_trait!(GaussianInt, RemAssign, rem_assign, rem);
+
+impl GaussianInt {
+    pub fn new<T, U>(re: T, im: U) -> GaussianInt
+    where
+        T: Into<BigInt>,
+        U: Into<BigInt>,
+    {
+        GaussianInt {
+            re: re.into(),
+            im: im.into(),
+        }
+    }
+
+    /// re^2 + im^2
+    pub fn norm(&self) -> BigInt {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+
+    pub fn conjugate(&self) -> GaussianInt {
+        GaussianInt {
+            re: self.re.clone(),
+            im: -self.im.clone(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.re == 0 && self.im == 0
+    }
+
+    /// 1, -1, i or -i
+    pub fn is_unit(&self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Quotient rounded to the nearest Gaussian integer, the remainder has a smaller norm
+    /// than the divisor, panics on a zero divisor.
+    pub fn div_rem(&self, divisor: &GaussianInt) -> (GaussianInt, GaussianInt) {
+        if divisor.is_zero() {
+            panic!("division by zero!");
+        }
+
+        let norm = divisor.norm();
+        let numerator = self.clone() * divisor.conjugate();
+        let quotient = GaussianInt {
+            re: divide_rounded(&numerator.re, &norm, RoundingMode::HalfEven),
+            im: divide_rounded(&numerator.im, &norm, RoundingMode::HalfEven),
+        };
+        let remainder = self.clone() - quotient.clone() * divisor.clone();
+
+        (quotient, remainder)
+    }
+
+    /// Greatest common divisor, normalized to the first quadrant.
+    pub fn gcd(&self, other: &GaussianInt) -> GaussianInt {
+        let mut left = self.clone();
+        let mut right = other.clone();
+
+        while !right.is_zero() {
+            let remainder = left.div_rem(&right).1;
+            left = right;
+            right = remainder;
+        }
+
+        left.normalize()
+    }
+
+    /// The associate with re > 0 and im >= 0, zero stays zero.
+    pub fn normalize(&self) -> GaussianInt {
+        let mut result = self.clone();
+
+        //zero edgecase
+        if result.is_zero() {
+            return result;
+        }
+
+        while !(result.re > 0 && result.im >= 0) {
+            result *= GaussianInt::new(0, 1);
+        }
+
+        result
+    }
+
+    /// Unit and Gaussian primes with exponents, primes are normalized and ordered by norm.
+    /// Panics on zero.
+    pub fn factorize(&self) -> (GaussianInt, Vec<(GaussianInt, u32)>) {
+        if self.is_zero() {
+            panic!("factorization of zero!");
+        }
+
+        let mut remaining = self.clone();
+        let mut factors = vec![];
+
+        for (prime, exponent) in factorize(&self.norm()) {
+            let candidates = if prime == 2 {
+                vec![GaussianInt::new(1, 1)]
+            } else if rem_euclid(&prime, &BigInt::from(4)) == 3 {
+                vec![GaussianInt::from(prime)]
+            } else {
+                let root = GaussianInt::prime_as_sum_of_squares(&prime);
+                vec![root.clone(), root.conjugate().normalize()]
+            };
+
+            //the norm bounds how many times a candidate can divide
+            for candidate in candidates {
+                let mut count = 0;
+                while count < exponent {
+                    let (quotient, remainder) = remaining.div_rem(&candidate);
+                    if !remainder.is_zero() {
+                        break;
+                    }
+                    remaining = quotient;
+                    count += 1;
+                }
+
+                if count > 0 {
+                    factors.push((candidate, count));
+                }
+            }
+        }
+
+        (remaining, factors)
+    }
+
+    /// a^2 + b^2 = n with 0 <= a <= b, None when n is not a sum of two squares.
+    pub fn sum_of_two_squares(number: &BigInt) -> Option<(BigInt, BigInt)> {
+        //zero and negative edgecases
+        if *number < 0 {
+            return None;
+        }
+        if *number == 0 {
+            return Some((BigInt::default(), BigInt::default()));
+        }
+
+        let mut result = GaussianInt::from(1);
+
+        for (prime, exponent) in factorize(number) {
+            if prime == 2 {
+                result *= GaussianInt::new(1, 1).pow(exponent);
+            } else if rem_euclid(&prime, &BigInt::from(4)) == 3 {
+                //primes 3 mod 4 need an even exponent
+                if exponent % 2 == 1 {
+                    return None;
+                }
+                result *= prime.pow(exponent / 2);
+            } else {
+                result *= GaussianInt::prime_as_sum_of_squares(&prime).pow(exponent);
+            }
+        }
+
+        let (re, im) = (result.re.abs(), result.im.abs());
+        if re <= im {
+            Some((re, im))
+        } else {
+            Some((im, re))
+        }
+    }
+
+    // the normalized Gaussian prime above a prime p = 1 mod 4, gcd(p, x + i) with x^2 = -1 mod p
+    fn prime_as_sum_of_squares(prime: &BigInt) -> GaussianInt {
+        let exponent = (prime.clone() - 1) / 4;
+        let mut non_residue = BigInt::from(2);
+
+        loop {
+            let root = mod_pow(&non_residue, &exponent, prime);
+            if mod_pow(&root, &BigInt::from(2), prime) == prime.clone() - 1 {
+                return GaussianInt::from(prime).gcd(&GaussianInt::new(root, 1));
+            }
+            non_residue += 1;
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::decimal::divide_rounded;
+use crate::float::bit_length;
+use crate::{BigFloat, BigInt, BigRational, Matrix, RoundingMode};
+
+impl Matrix<BigInt> {
+    /// LLL reduction of the rows with the Lovász constant `delta` in (1/4, 1], integer-only
+    /// (de Weger). The rows have to be linearly independent, panics otherwise.
+    pub fn lll(&self, delta: &BigRational) -> Matrix<BigInt> {
+        assert!(
+            *delta > BigRational::new(1, 4) && *delta <= 1,
+            "delta has to be in (1/4, 1]!"
+        );
+
+        let mut basis = self.to_rows();
+        let size = basis.len();
+
+        //empty basis edgecase
+        if size == 0 {
+            return self.clone();
+        }
+
+        //d[i] is the Gram determinant of the first i rows, lambda[k][j] = d[j + 1] * mu[k][j]
+        let mut d = vec![BigInt::from(1), dot(&basis[0], &basis[0])];
+        d.resize(size + 1, BigInt::default());
+        let mut lambda = vec![vec![BigInt::default(); size]; size];
+        assert!(d[1] != 0, "basis is linearly dependent!");
+
+        let mut k = 1;
+        let mut k_max = 0;
+
+        while k < size {
+            if k > k_max {
+                k_max = k;
+                for j in 0..=k {
+                    let mut u = dot(&basis[k], &basis[j]);
+                    for i in 0..j {
+                        u = (d[i + 1].clone() * u - lambda[k][i].clone() * lambda[j][i].clone())
+                            / d[i].clone();
+                    }
+
+                    if j < k {
+                        lambda[k][j] = u;
+                    } else {
+                        assert!(u != 0, "basis is linearly dependent!");
+                        d[k + 1] = u;
+                    }
+                }
+            }
+
+            reduce(&mut basis, &mut lambda, &d, k, k - 1);
+
+            //Lovász condition delta * d[k]^2 <= d[k + 1] * d[k - 1] + lambda^2, scaled by the denominator
+            let lovasz = delta.denominator().clone()
+                * (d[k + 1].clone() * d[k - 1].clone()
+                    + lambda[k][k - 1].clone() * lambda[k][k - 1].clone());
+            if lovasz < delta.numerator().clone() * d[k].clone() * d[k].clone() {
+                swap(&mut basis, &mut lambda, &mut d, k, k_max);
+                k = (k - 1).max(1);
+            } else {
+                for l in (0..k - 1).rev() {
+                    reduce(&mut basis, &mut lambda, &d, k, l);
+                }
+                k += 1;
+            }
+        }
+
+        Matrix::from_rows(basis, self.cols())
+    }
+}
+
+/// Integer coefficients m, not all zero, with m_0 * x_0 + ... + m_n * x_n = 0 up to
+/// `precision` bits, found by LLL. A relation only counts when its coefficients have
+/// fewer than precision / (2 * values.len()) bits, since at any precision there are
+/// spurious relations with about precision / values.len() bits. None otherwise.
+pub fn integer_relation(values: &[BigFloat], precision: u64) -> Option<Vec<BigInt>> {
+    let size = values.len();
+
+    //empty edgecase
+    if size == 0 {
+        return None;
+    }
+
+    //each row is a unit vector followed by the value scaled to an integer
+    let basis: Vec<Vec<BigInt>> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let scaled = BigFloat::new(
+                value.mantissa().clone(),
+                value.exponent() + precision as i64,
+                value.precision(),
+            );
+
+            let mut row: Vec<BigInt> = (0..size).map(|j| BigInt::from(u8::from(i == j))).collect();
+            row.push(scaled.to_bigint(RoundingMode::HalfEven));
+            row
+        })
+        .collect();
+
+    let reduced = Matrix::new(basis).lll(&BigRational::new(99, 100));
+    let mut relation = reduced.row(0)[..size].to_vec();
+    let residual = reduced.row(0)[size].abs();
+
+    //every scaled value is off by at most 1/2
+    let bound = relation
+        .iter()
+        .fold(BigInt::default(), |sum, m| sum + m.abs());
+    let coefficient_bits = precision / (2 * size as u64).max(1);
+    if relation.iter().all(|m| *m == 0)
+        || residual > bound
+        || relation.iter().any(|m| bit_length(m) >= coefficient_bits)
+    {
+        return None;
+    }
+
+    //the first non-zero coefficient is positive
+    if relation.iter().find(|m| **m != 0).is_some_and(|m| *m < 0) {
+        relation = relation.into_iter().map(|m| -m).collect();
+    }
+
+    Some(relation)
+}
+
+fn dot(left: &[BigInt], right: &[BigInt]) -> BigInt {
+    left.iter()
+        .zip(right)
+        .fold(BigInt::default(), |sum, (a, b)| sum + a.clone() * b.clone())
+}
+
+// size reduction of row k against row l
+fn reduce(basis: &mut [Vec<BigInt>], lambda: &mut [Vec<BigInt>], d: &[BigInt], k: usize, l: usize) {
+    if lambda[k][l].abs() * 2 <= d[l + 1] {
+        return;
+    }
+
+    let quotient = divide_rounded(&lambda[k][l], &d[l + 1], RoundingMode::HalfEven);
+
+    for col in 0..basis[k].len() {
+        let subtrahend = quotient.clone() * basis[l][col].clone();
+        basis[k][col] -= subtrahend;
+    }
+    lambda[k][l] -= quotient.clone() * d[l + 1].clone();
+    let (upper, lower) = lambda.split_at_mut(k);
+    for (entry, source) in lower[0].iter_mut().zip(&upper[l]).take(l) {
+        *entry -= quotient.clone() * source.clone();
+    }
+}
+
+// exchanges rows k - 1 and k, updating the Gram determinants and lambdas
+fn swap(
+    basis: &mut [Vec<BigInt>],
+    lambda: &mut [Vec<BigInt>],
+    d: &mut [BigInt],
+    k: usize,
+    k_max: usize,
+) {
+    basis.swap(k, k - 1);
+    let (upper, lower) = lambda.split_at_mut(k);
+    upper[k - 1][..k - 1].swap_with_slice(&mut lower[0][..k - 1]);
+
+    let l = lambda[k][k - 1].clone();
+    let b = (d[k - 1].clone() * d[k + 1].clone() + l.clone() * l.clone()) / d[k].clone();
+
+    for row in lambda.iter_mut().take(k_max + 1).skip(k + 1) {
+        let t = row[k].clone();
+        row[k] = (d[k + 1].clone() * row[k - 1].clone() - l.clone() * t.clone()) / d[k].clone();
+        row[k - 1] = (b.clone() * t + l.clone() * row[k].clone()) / d[k + 1].clone();
+    }
+
+    d[k] = b;
+}
@@ -1,15 +1,77 @@
-use num_traits::{Pow, ToPrimitive, Zero};
+use num_traits::{Pow, ToPrimitive};
 
 use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt::{self, Alignment, Binary, Display, LowerHex, UpperHex};
+use std::hash::{Hash, Hasher};
 use std::ops::*;
 use std::str::FromStr;
 
+macro_rules! assign_trait {
+    ($type:ty, $trait:ident, $function:ident, $operation:ident) => {
+        impl<T> $trait<T> for $type
+        where
+            T: Into<$type>,
+        {
+            fn $function(&mut self, rhs: T) {
+                *self = self.clone().$operation(rhs);
+            }
+        }
+    };
+}
+
+mod biguint;
+mod bytes;
+mod combinatorics;
+mod constants;
+mod continued_fraction;
+mod decimal;
+mod diophantine;
+mod elementary;
+mod fixed;
+mod float;
+mod gaussian;
+mod lattice;
+mod lucas;
+mod matrix;
+mod normal_form;
+mod number_theory;
+mod polynomial;
+#[cfg(feature = "rand")]
+mod random;
+mod rational;
+mod sequences;
+mod traits;
+mod wrapping;
+
+pub use biguint::BigUint;
+pub use constants::{e_digits, pi_digits};
+pub use continued_fraction::{
+    Convergents, continued_fraction, from_continued_fraction, sqrt_continued_fraction,
+};
+pub use decimal::{BigDecimal, RoundingMode};
+pub use diophantine::{LinearDiophantine, Pell, linear_diophantine, linear_diophantine_system};
+pub use fixed::{I256, I512, I1024, Int, U256, U512, U1024, Uint};
+pub use float::BigFloat;
+pub use gaussian::GaussianInt;
+pub use lattice::integer_relation;
+pub use lucas::{lucas_lehmer, lucas_sequence};
+pub use matrix::Matrix;
+pub use number_theory::{crt, discrete_log};
+pub use polynomial::Polynomial;
+#[cfg(feature = "rand")]
+pub use random::RandBigInt;
+pub use rational::BigRational;
+pub use sequences::{Bell, Fibonacci, Lucas, Partitions};
+
 #[derive(Debug)]
 pub enum BigIntError {
     NaN,
     LargeNumber,
+    SmallNumber,
+    NegativeNumber,
+    FractionalNumber,
+    DivisionByZero,
 }
 
 impl Error for BigIntError {}
@@ -19,6 +81,10 @@ impl Display for BigIntError {
         match self {
             BigIntError::NaN => write!(f, "Not a Number"),
             BigIntError::LargeNumber => write!(f, "Too large"),
+            BigIntError::SmallNumber => write!(f, "Too small"),
+            BigIntError::NegativeNumber => write!(f, "Negative number"),
+            BigIntError::FractionalNumber => write!(f, "Not an integer"),
+            BigIntError::DivisionByZero => write!(f, "Division by zero"),
         }
     }
 }
@@ -26,14 +92,14 @@ impl Display for BigIntError {
 #[derive(Clone, Eq, Debug)]
 pub struct BigInt {
     positive: bool,
-    numbers: Vec<u8>,
+    magnitude: BigUint,
 }
 
 impl Default for BigInt {
     fn default() -> Self {
         BigInt {
             positive: true,
-            numbers: vec![0],
+            magnitude: BigUint::default(),
         }
     }
 }
@@ -48,7 +114,7 @@ impl FromStr for BigInt {
             return Err(BigIntError::NaN);
         }
 
-        let mut positive = !(string_of_numbers.starts_with('-'));
+        let positive = !(string_of_numbers.starts_with('-'));
         let mut numbers: Vec<u8> = Vec::new();
 
         //if negative - remove '-'
@@ -66,11 +132,15 @@ impl FromStr for BigInt {
             numbers.push(char.to_digit(10).unwrap().to_u8().unwrap());
         }
 
-        if numbers.as_slice() == [0] {
-            positive = true;
+        //lone minus sign
+        if numbers.is_empty() {
+            return Err(BigIntError::NaN);
         }
 
-        Ok(BigInt { positive, numbers })
+        Ok(BigInt::from_magnitude(
+            positive,
+            BigUint::from_digits(numbers),
+        ))
     }
 }
 
@@ -78,32 +148,11 @@ macro_rules! from_int {
     ($($t:ty),*)=>{
         $(
             impl From<$t> for BigInt{
-                fn from(mut original_number: $t) -> Self {
-
-                //zero edgecase
-                if original_number == 0 {
-                    return BigInt::default();
-                }
-
-                let mut numbers = Vec::new();
-                let positive = !(original_number < 0);
-
-                //negative number
-                if original_number < 0 {
-                    original_number = -original_number;
-                }
-
-                //transformation of digits
-                while original_number != 0{
-                    numbers.push((original_number % 10).to_u8().unwrap());
-                    original_number /= 10;
-                }
-
-                numbers.reverse();
-
-                //return value
-                BigInt {positive, numbers}
-
+                fn from(original_number: $t) -> Self {
+                    BigInt::from_magnitude(
+                        original_number >= 0,
+                        original_number.unsigned_abs().into(),
+                    )
                 }
             }
         )*
@@ -114,95 +163,75 @@ macro_rules! from_uint {
     ($($t:ty),*)=>{
         $(
             impl From<$t> for BigInt{
-                fn from(mut original_number: $t) -> Self {
-
-                //zero edgecase
-                if original_number == 0 {
-                    return BigInt::default();
-                }
-
-                let mut numbers = Vec::new();
-
-                //transformation of digits
-                while original_number != 0{
-                    numbers.push((original_number % 10).to_u8().unwrap());
-                    original_number /= 10;
-                }
-
-                numbers.reverse();
-
-                //return value
-                BigInt {
-                    positive: true, numbers}
-
+                fn from(original_number: $t) -> Self {
+                    BigInt::from_magnitude(true, original_number.into())
                 }
             }
         )*
     }
 }
 
-from_int!(i8, i16, i32, i64, i128);
-from_uint!(u8, u16, u32, u64, u128);
+from_int!(i8, i16, i32, i64, i128, isize);
+from_uint!(u8, u16, u32, u64, u128, usize);
 
-macro_rules! try_into_uint {
+macro_rules! try_from_uint {
     ($($t:ty),*) => {
-        $(impl TryInto<$t> for BigInt{
-            type Error = BigIntError;
-            fn try_into(self) -> Result<$t, Self::Error> {
-
-                if self > <$t>::MAX {
-                    return Err(BigIntError::LargeNumber);
-                }
-                if self == 0 {
-                    return Ok(0 as $t);
+        $(
+            impl TryFrom<&BigInt> for $t {
+                type Error = BigIntError;
+                fn try_from(number: &BigInt) -> Result<Self, Self::Error> {
+                    if !number.positive {
+                        return Err(BigIntError::NegativeNumber);
+                    }
+
+                    number
+                        .to_u128()
+                        .and_then(|number| <$t>::try_from(number).ok())
+                        .ok_or(BigIntError::LargeNumber)
                 }
+            }
 
-                let mut result: $t = 0;
-
-                for (position, number) in self.numbers.iter().rev().enumerate() {
-                    result += BigInt::create_new_digit(position, number) as $t;
+            impl TryFrom<BigInt> for $t {
+                type Error = BigIntError;
+                fn try_from(number: BigInt) -> Result<Self, Self::Error> {
+                    <$t>::try_from(&number)
                 }
-
-                Ok(result)
             }
-        })*
-
+        )*
     };
 }
 
-macro_rules! try_into_int {
+macro_rules! try_from_int {
     ($($t:ty),*) => {
-        $(impl TryInto<$t> for BigInt{
-            type Error = BigIntError;
-            fn try_into(self) -> Result<$t, Self::Error> {
-
-                if self > <$t>::MAX {
-                    return Err(BigIntError::LargeNumber);
-                }
-                if self == 0 {
-                    return Ok(0 as $t);
-                }
-
-                let mut result: $t = 0;
-
-                for (position, number) in self.numbers.iter().rev().enumerate() {
-                    result += BigInt::create_new_digit(position, number) as $t;
+        $(
+            impl TryFrom<&BigInt> for $t {
+                type Error = BigIntError;
+                fn try_from(number: &BigInt) -> Result<Self, Self::Error> {
+                    let out_of_range = if number.positive {
+                        BigIntError::LargeNumber
+                    } else {
+                        BigIntError::SmallNumber
+                    };
+
+                    number
+                        .to_i128()
+                        .and_then(|number| <$t>::try_from(number).ok())
+                        .ok_or(out_of_range)
                 }
+            }
 
-                if !self.positive {
-                   result *= -1;
+            impl TryFrom<BigInt> for $t {
+                type Error = BigIntError;
+                fn try_from(number: BigInt) -> Result<Self, Self::Error> {
+                    <$t>::try_from(&number)
                 }
-
-
-                Ok(result)
             }
-        })*
-
+        )*
     };
 }
 
-try_into_uint!(u8, u16, u32, u64, u128);
-try_into_int!(i8, i16, i32, i64, i128);
+try_from_uint!(u8, u16, u32, u64, u128, usize);
+try_from_int!(i8, i16, i32, i64, i128, isize);
 
 impl Display for BigInt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -215,7 +244,7 @@ impl Display for BigInt {
             output.push('+');
         }
 
-        for digit in &self.numbers {
+        for digit in &self.magnitude.numbers {
             output.push_str(&digit.to_string());
         }
 
@@ -284,11 +313,18 @@ impl PartialEq for BigInt {
         if self.positive != other.positive {
             false
         } else {
-            self.numbers == other.numbers
+            self.magnitude == other.magnitude
         }
     }
 }
 
+impl Hash for BigInt {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.positive.hash(state);
+        self.magnitude.hash(state);
+    }
+}
+
 macro_rules! eq_with_int {
     ($($t:ty),*) => {
         $(
@@ -301,7 +337,9 @@ macro_rules! eq_with_int {
     };
 }
 
-eq_with_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+eq_with_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
 
 impl PartialEq<&str> for BigInt {
     fn eq(&self, other: &&str) -> bool {
@@ -334,50 +372,25 @@ macro_rules! partial_ord_intieger {
     };
 }
 
-partial_ord_intieger!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
-
-impl PartialOrd for BigInt {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self == other {
-            return Some(Ordering::Equal);
-        }
-
-        let mut greater = false;
-
-        //check +/-
-        if self.positive != other.positive {
-            greater = self.positive;
-        }
-        //check length
-        else if self.numbers.len() != other.numbers.len() {
-            if self.positive {
-                greater = self.numbers.len() > other.numbers.len();
-            } else {
-                greater = self.numbers.len() < other.numbers.len();
-            }
-        }
-        //compare digits
-        else {
-            let numbers_iterator = self.numbers.iter().zip(other.numbers.iter());
-
-            for (left, right) in numbers_iterator {
-                if left != right {
-                    greater = left > right;
-                    break;
-                }
-            }
+partial_ord_intieger!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
 
-            if !self.positive {
-                greater = !greater;
-            }
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //zero is always positive, so signs can be compared first
+        match (self.positive, other.positive) {
+            (true, true) => self.magnitude.cmp(&other.magnitude),
+            (false, false) => other.magnitude.cmp(&self.magnitude),
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
         }
+    }
+}
 
-        //return value
-        if greater {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Less)
-        }
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -393,19 +406,6 @@ impl PartialOrd<&str> for BigInt {
     }
 }
 
-macro_rules! assign_trait {
-    ($trait:ident, $function:ident, $operation:ident) => {
-        impl<T> $trait<T> for BigInt
-        where
-            T: Into<BigInt>,
-        {
-            fn $function(&mut self, rhs: T) {
-                *self = self.clone().$operation(rhs);
-            }
-        }
-    };
-}
-
 impl<T> Add<T> for BigInt
 where
     T: Into<BigInt>,
@@ -414,61 +414,29 @@ where
     fn add(self, rhs: T) -> Self::Output {
         let right: BigInt = rhs.into();
 
-        // X + 0 edgecase
-        if self == 0 {
-            return right;
-        }
-        if right == 0 {
-            return self;
-        }
-
-        // negative numbers edgecases
-        if !self.positive && right.positive {
-            // -X + Y => Y - X => -(X - Y)
-            //self.positive = true;
-            return right - (-self);
-        } else if self.positive && !right.positive {
-            //X - Y
-            //right.positive = true;
-            return self - (-right);
-        }
-
-        let mut result = BigInt {
-            positive: self.positive,
-            numbers: vec![],
-        };
-
-        let (longer, shorter) = BigInt::equalize_vectors_length(self.numbers, right.numbers);
-
-        let numbers_set = longer.iter().zip(shorter.iter());
-
-        let mut new_number;
-        let mut carry: u8 = 0;
-
-        for number_set in numbers_set {
-            new_number = 0;
-
-            new_number += number_set.0;
-            new_number += number_set.1;
-            new_number += carry;
-
-            let new_digit = new_number % 10;
-            carry = new_number / 10;
-
-            result.numbers.push(new_digit);
+        if self.positive == right.positive {
+            return BigInt::from_magnitude(
+                self.positive,
+                self.magnitude.add_magnitude(&right.magnitude),
+            );
         }
 
-        if carry != 0 {
-            result.numbers.push(carry);
+        // X + (-Y) => the larger magnitude decides the sign
+        if self.magnitude >= right.magnitude {
+            BigInt::from_magnitude(
+                self.positive,
+                self.magnitude.sub_magnitude(&right.magnitude),
+            )
+        } else {
+            BigInt::from_magnitude(
+                right.positive,
+                right.magnitude.sub_magnitude(&self.magnitude),
+            )
         }
-
-        result.numbers.reverse();
-
-        result
     }
 }
 
-assign_trait!(AddAssign, add_assign, add);
+assign_trait!(BigInt, AddAssign, add_assign, add);
 
 impl<T> Sub<T> for BigInt
 where
@@ -476,90 +444,15 @@ where
 {
     type Output = Self;
 
-    fn sub(mut self, rhs: T) -> Self::Output {
-        let mut right: BigInt = rhs.into();
-
-        // 0 - X edgecase
-        if self == 0 {
-            return -right;
-        }
-        if right == 0 {
-            return self;
-        }
-        if right == self {
-            return 0.into();
-        }
-
-        // negative numbers edgecases
-        if !self.positive && right.positive {
-            // -X - Y => -(X+Y)
-            self.positive = true;
-            return -(self + right);
-        } else if self.positive && !right.positive {
-            //X - (-Y)
-            right.positive = true;
-            return self + right;
-        } else if !self.positive && !right.positive {
-            // -X - (-Y) => -X + Y => Y - X
-            self.positive = true;
-            right.positive = true;
-            return right - self;
-        }
-
-        if self < right {
-            return -(right - self);
-        }
-
-        let mut result: BigInt = BigInt {
-            positive: self >= right,
-            numbers: vec![],
-        };
-
-        self.numbers.reverse();
-        right.numbers.reverse();
-
-        if self.numbers.len() >= right.numbers.len() {
-            right
-                .numbers
-                .extend(vec![0; self.numbers.len() - right.numbers.len()]);
-        } else {
-            self.numbers
-                .extend(vec![0; right.numbers.len() - self.numbers.len()]);
-        }
-
-        let numbers_set = self.numbers.iter().zip(right.numbers.iter());
-
-        let mut new_number: i8;
-        let mut carry = 0;
-
-        for number_set in numbers_set {
-            new_number = 0;
-
-            new_number += *number_set.0 as i8;
-            new_number -= *number_set.1 as i8;
-            new_number -= carry;
-
-            if new_number < 0 {
-                new_number += 10;
-                carry = 1;
-            } else {
-                carry = 0;
-            }
-
-            result.numbers.push(new_number as u8);
-        }
-
-        result.numbers.reverse();
-
-        while result.numbers.first().unwrap_or(&1).is_zero() {
-            result.numbers.remove(0);
-        }
+    fn sub(self, rhs: T) -> Self::Output {
+        let right: BigInt = rhs.into();
 
-        result
+        // X - Y => X + (-Y)
+        self + (-right)
     }
 }
 
-assign_trait!(SubAssign, sub_assign, sub);
+assign_trait!(BigInt, SubAssign, sub_assign, sub);
 
 impl<T> Mul<T> for BigInt
 where
@@ -569,69 +462,14 @@ where
     fn mul(self, rhs: T) -> Self::Output {
         let right: BigInt = rhs.into();
 
-        //X * 0 edgecase
-        if self == 0 || right == 0 {
-            return BigInt::default();
-        }
-
-        if self == 1 {
-            return right;
-        }
-        if right == 1 {
-            return self;
-        }
-
-        if self == -1 {
-            return -right;
-        }
-        if right == -1 {
-            return -self;
-        }
-
-        let mut result = BigInt::default();
-        let mut sub_total;
-        let mut new_digit;
-        let mut carry;
-
-        for (position, &left) in self.numbers.iter().rev().enumerate() {
-            // *0 edgecase
-            if left == 0 {
-                continue;
-            }
-
-            sub_total = BigInt {
-                positive: true,
-                numbers: vec![],
-            };
-
-            carry = 0;
-
-            //right digit loop
-            for &right in right.numbers.iter().rev() {
-                new_digit = left * right;
-                new_digit += carry;
-                sub_total.numbers.push(new_digit % 10);
-                carry = new_digit / 10;
-            }
-
-            if !carry.is_zero() {
-                sub_total.numbers.push(carry);
-            }
-
-            sub_total.numbers.reverse();
-
-            //zero offset
-            sub_total.numbers.extend(vec![0; position]);
-
-            result += sub_total;
-        }
-
-        result.positive = self.positive == right.positive;
-        result
+        BigInt::from_magnitude(
+            self.positive == right.positive,
+            self.magnitude.mul_magnitude(&right.magnitude),
+        )
     }
 }
 
-assign_trait!(MulAssign, mul_assign, mul);
+assign_trait!(BigInt, MulAssign, mul_assign, mul);
 
 impl<T> Div<T> for BigInt
 where
@@ -644,24 +482,12 @@ where
         if right == 0 {
             panic!("division by zero!");
         }
-        if self == 0 {
-            return BigInt::default();
-        }
-        if right == 1 {
-            return self;
-        }
-        if self == 1 {
-            if right == 1 {
-                return 1.into();
-            }
-            return BigInt::default();
-        }
 
         BigInt::divide_with_remainder(self, right).0
     }
 }
 
-assign_trait!(DivAssign, div_assign, div);
+assign_trait!(BigInt, DivAssign, div_assign, div);
 
 impl<T> Rem<T> for BigInt
 where
@@ -674,15 +500,12 @@ where
         if right == 0 {
             panic!("division by zero!");
         }
-        if self == 0 || right == 1 {
-            return 0.into();
-        }
 
         BigInt::divide_with_remainder(self, right).1
     }
 }
 
-assign_trait!(RemAssign, rem_assign, rem);
+assign_trait!(BigInt, RemAssign, rem_assign, rem);
 
 impl<T> Pow<T> for BigInt
 where
@@ -691,7 +514,7 @@ where
     type Output = Self;
 
     fn pow(self, rhs: T) -> Self::Output {
-        let mut right: BigInt = rhs.into();
+        let right: BigInt = rhs.into();
 
         if self == 0 || self == 1 || right == 1 {
             return self;
@@ -708,10 +531,13 @@ where
 
         let mut result: BigInt = 1.into();
 
-        while right != 0 {
-            result *= self.clone();
+        //square and multiply from the most significant bit
+        for bit in right.to_binary().1 {
+            result = result.clone() * result;
 
-            right -= 1;
+            if bit {
+                result *= self.clone();
+            }
         }
 
         result
@@ -728,7 +554,7 @@ where
     }
 }
 
-assign_trait!(BitAndAssign, bitand_assign, bitand);
+assign_trait!(BigInt, BitAndAssign, bitand_assign, bitand);
 
 impl<T> BitOr<T> for BigInt
 where
@@ -740,7 +566,7 @@ where
     }
 }
 
-assign_trait!(BitOrAssign, bitor_assign, bitor);
+assign_trait!(BigInt, BitOrAssign, bitor_assign, bitor);
 
 impl<T> BitXor<T> for BigInt
 where
@@ -752,7 +578,7 @@ where
     }
 }
 
-assign_trait!(BitXorAssign, bitxor_assign, bitxor);
+assign_trait!(BigInt, BitXorAssign, bitxor_assign, bitxor);
 
 impl<T> Shl<T> for BigInt
 where
@@ -772,7 +598,7 @@ where
     }
 }
 
-assign_trait!(ShlAssign, shl_assign, shl);
+assign_trait!(BigInt, ShlAssign, shl_assign, shl);
 
 impl<T> Shr<T> for BigInt
 where
@@ -792,7 +618,7 @@ where
     }
 }
 
-assign_trait!(ShrAssign, shr_assign, shr);
+assign_trait!(BigInt, ShrAssign, shr_assign, shr);
 
 impl BigInt {
     pub fn new() -> BigInt {
@@ -805,7 +631,8 @@ impl BigInt {
         prefix
             .into_iter()
             .chain(
-                self.numbers
+                self.magnitude
+                    .numbers
                     .iter()
                     .map(|&number| BigInt::number_to_word(number)),
             )
@@ -872,100 +699,38 @@ impl BigInt {
             return Err(BigIntError::NaN);
         }
 
-        Ok(BigInt { positive, numbers })
-    }
-
-    fn is_even(&self) -> bool {
-        (*self.numbers.last().unwrap() % 2).is_zero()
+        Ok(BigInt::from_magnitude(
+            positive,
+            BigUint::from_digits(numbers),
+        ))
     }
 
-    fn to_binary(&self) -> (bool, Vec<bool>) {
-        if *self == 0 {
-            return (true, vec![false]);
-        }
-
-        let mut final_vec = vec![];
-
-        let mut number = self.clone();
-
-        let positive = number.positive;
-        number.positive = true;
-
-        while number != 0 {
-            if number.is_even() {
-                final_vec.push(false);
-            } else {
-                final_vec.push(true);
-            }
-            number /= 2;
+    // zero is always positive
+    fn from_magnitude(positive: bool, magnitude: BigUint) -> BigInt {
+        BigInt {
+            positive: positive || magnitude == 0_u8,
+            magnitude,
         }
-
-        final_vec.reverse();
-
-        (positive, final_vec)
     }
 
-    fn from_binary(positive: bool, binary: Vec<bool>) -> BigInt {
-        if binary.is_empty() {
-            return BigInt::default();
-        }
-
-        let mut result = BigInt::default();
-
-        for (position, bit) in binary.iter().rev().enumerate() {
-            if *bit {
-                result += 2.pow(position);
-            }
-        }
-
-        result.positive = positive;
-
-        result
+    fn is_even(&self) -> bool {
+        self.magnitude.is_even()
     }
 
-    fn number_to_hexa(number: BigInt) -> char {
-        let number: u8 = number.try_into().unwrap_or(0);
-
-        match number {
-            0 => '0',
-            1 => '1',
-            2 => '2',
-            3 => '3',
-            4 => '4',
-            5 => '5',
-            6 => '6',
-            7 => '7',
-            8 => '8',
-            9 => '9',
-            10 => 'A',
-            11 => 'B',
-            12 => 'C',
-            13 => 'D',
-            14 => 'E',
-            15 => 'F',
-            _ => unreachable!(),
-        }
+    fn abs(&self) -> BigInt {
+        BigInt::from_magnitude(true, self.magnitude.clone())
     }
 
-    fn to_hexa_vec(&self) -> Vec<char> {
-        let mut final_vec = vec![];
-        let mut number = self.clone();
-
-        number.positive = true;
-
-        while number > 0 {
-            let divided = BigInt::divide_with_remainder(number, 16.into());
-            final_vec.push(BigInt::number_to_hexa(divided.1));
-            number = divided.0;
-        }
-
-        final_vec.reverse();
+    fn to_binary(&self) -> (bool, Vec<bool>) {
+        (self.positive, self.magnitude.to_binary())
+    }
 
-        final_vec
+    fn from_binary(positive: bool, binary: Vec<bool>) -> BigInt {
+        BigInt::from_magnitude(positive, BigUint::from_binary(&binary))
     }
 
     fn create_hexa_string(&self, f: &mut fmt::Formatter<'_>, uppercase: bool) -> String {
-        let hexa = self.to_hexa_vec();
+        let hexa = self.magnitude.to_hexa_vec();
         let mut output = String::new();
 
         if !self.positive {
@@ -992,23 +757,6 @@ impl BigInt {
         output
     }
 
-    fn equalize_vectors_length(left: Vec<u8>, right: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
-        let (mut longer, mut shorter) = {
-            if left.len() > right.len() {
-                (left, right)
-            } else {
-                (right, left)
-            }
-        };
-
-        longer.reverse();
-        shorter.reverse();
-
-        shorter.extend(vec![0; longer.len() - shorter.len()]);
-
-        (shorter, longer)
-    }
-
     fn binary_operation<F, T>(self, rhs: T, bit_operation: F) -> BigInt
     where
         F: Fn(bool, bool) -> bool,
@@ -1016,77 +764,22 @@ impl BigInt {
     {
         let right: BigInt = rhs.into();
 
-        let (left_positive, mut left) = self.to_binary();
-        let (right_positive, mut right) = right.to_binary();
+        let positive = bit_operation(self.positive, right.positive);
+        let binary = self
+            .magnitude
+            .combine_binary(&right.magnitude, bit_operation);
 
-        left.reverse();
-        right.reverse();
-
-        let (longer, mut shorter) = if left.len() > right.len() {
-            (left, right)
-        } else {
-            (right, left)
-        };
-
-        shorter.extend(vec![false; longer.len() - shorter.len()]);
-
-        let binary_set = longer.iter().zip(shorter.iter());
-
-        let result: Vec<bool> = binary_set
-            .rev()
-            .map(|(left, right)| bit_operation(*left, *right))
-            .collect();
-
-        BigInt::from_binary(bit_operation(left_positive, right_positive), result)
+        BigInt::from_binary(positive, binary)
     }
 
-    fn divide_with_remainder(mut left: BigInt, mut right: BigInt) -> (BigInt, BigInt) {
-        let mut result = BigInt {
-            positive: left.positive == right.positive,
-            numbers: vec![],
-        };
-
-        let mut partial_sum = BigInt {
-            positive: true,
-            numbers: vec![],
-        };
-
-        left.positive = true;
-        right.positive = true;
-
-        let mut new_digit;
-
-        for digit in left.numbers.iter() {
-            partial_sum.numbers.push(*digit);
-            new_digit = 0;
-
-            while partial_sum >= right {
-                partial_sum -= right.clone();
-                new_digit += 1;
-            }
-
-            result.numbers.push(new_digit);
+    // truncating division, the remainder takes the sign of the dividend
+    fn divide_with_remainder(left: BigInt, right: BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = left.magnitude.divide_with_remainder(&right.magnitude);
 
-            if partial_sum.numbers == vec![0] {
-                partial_sum.numbers.clear();
-            }
-        }
-
-        while result.numbers.first().unwrap_or(&1).is_zero() {
-            result.numbers.remove(0);
-        }
-
-        if result.numbers.is_empty() {
-            return (BigInt::default(), partial_sum);
-        }
-
-        if partial_sum.numbers.is_empty() {
-            partial_sum = BigInt::default();
-        }
-
-        partial_sum.positive = result.positive;
-
-        (result, partial_sum)
+        (
+            BigInt::from_magnitude(left.positive == right.positive, quotient),
+            BigInt::from_magnitude(left.positive, remainder),
+        )
     }
 
     fn add_alignment(output: &mut String, f: &mut fmt::Formatter<'_>) {
@@ -1119,18 +812,6 @@ impl BigInt {
             }
         }
     }
-
-    fn create_new_digit(position: usize, number: &u8) -> u64 {
-        let number = *number as u64;
-
-        if number == 0 {
-            return match position {
-                0 => 0,
-                _ => 10.pow(position),
-            } as u64;
-        }
-        number * 10_u64.pow(position as u32)
-    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,192 @@
+use crate::BigInt;
+use crate::number_theory::{mod_mul, rem_euclid};
+
+/// Evaluates the Lucas sequences `U_k(P, Q)` and `V_k(P, Q)` modulo `n`.
+///
+/// Returns `(U_k mod n, V_k mod n)`. Works for any positive modulus, even ones
+/// where 2 isn't invertible. Panics if `n` isn't positive or `k` is negative.
+pub fn lucas_sequence(p: &BigInt, q: &BigInt, k: &BigInt, n: &BigInt) -> (BigInt, BigInt) {
+    assert!(*n > 0, "modulus has to be positive!");
+    assert!(*k >= 0, "index has to be non-negative!");
+
+    let p = rem_euclid(p, n);
+    let q = rem_euclid(q, n);
+
+    //(U_k, U_{k+1})
+    let mut current = BigInt::default();
+    let mut next = rem_euclid(&1.into(), n);
+
+    for bit in k.to_binary().1 {
+        // U_2k = U_k * (2 * U_{k+1} - P * U_k)
+        // U_2k+1 = U_{k+1}^2 - Q * U_k^2
+        let doubled = mod_mul(&current, &(next.clone() * 2 - mod_mul(&p, &current, n)), n);
+        let doubled_next = rem_euclid(
+            &(mod_mul(&next, &next, n) - mod_mul(&q, &mod_mul(&current, &current, n), n)),
+            n,
+        );
+
+        if bit {
+            // U_2k+2 = P * U_2k+1 - Q * U_2k
+            next = rem_euclid(
+                &(mod_mul(&p, &doubled_next, n) - mod_mul(&q, &doubled, n)),
+                n,
+            );
+            current = doubled_next;
+        } else {
+            current = doubled;
+            next = doubled_next;
+        }
+    }
+
+    // V_k = 2 * U_{k+1} - P * U_k
+    let v = rem_euclid(&(next * 2 - mod_mul(&p, &current, n)), n);
+
+    (current, v)
+}
+
+/// Lucas–Lehmer test, returns whether the Mersenne number `2^p - 1` is prime.
+///
+/// The residues are kept in binary limbs so that reduction modulo `2^p - 1`
+/// is a shift and an addition instead of a division.
+pub fn lucas_lehmer(p: u64) -> bool {
+    if p == 2 {
+        return true;
+    }
+
+    //2^p - 1 is composite for composite p
+    if p < 2
+        || (2..)
+            .take_while(|d| d * d <= p)
+            .any(|d| p.is_multiple_of(d))
+    {
+        return false;
+    }
+
+    let mut residue = vec![4];
+
+    for _ in 0..p - 2 {
+        residue = reduce_mersenne(square(&residue), p);
+        residue = subtract_two_mersenne(residue, p);
+    }
+
+    residue.iter().all(|&limb| limb == 0)
+}
+
+// x mod (2^p - 1) = (x & (2^p - 1)) + (x >> p)
+fn reduce_mersenne(mut limbs: Vec<u64>, p: u64) -> Vec<u64> {
+    loop {
+        let high = shift_right(&limbs, p);
+        let low = mask_low_bits(&limbs, p);
+
+        if high.iter().all(|&limb| limb == 0) {
+            limbs = low;
+            break;
+        }
+
+        limbs = add(&low, &high);
+    }
+
+    //2^p - 1 itself is congruent to zero
+    if limbs == mersenne(p) {
+        return vec![0; limbs.len()];
+    }
+
+    limbs
+}
+
+fn subtract_two_mersenne(mut limbs: Vec<u64>, p: u64) -> Vec<u64> {
+    if limbs.iter().skip(1).all(|&limb| limb == 0) && limbs[0] < 2 {
+        limbs = add(&limbs, &mersenne(p));
+    }
+
+    let mut borrow = 2;
+    for limb in limbs.iter_mut() {
+        let (difference, overflow) = limb.overflowing_sub(borrow);
+        *limb = difference;
+        borrow = overflow as u64;
+    }
+
+    limbs
+}
+
+fn mersenne(p: u64) -> Vec<u64> {
+    let mut limbs = vec![u64::MAX; (p / 64) as usize];
+    if !p.is_multiple_of(64) {
+        limbs.push((1 << (p % 64)) - 1);
+    }
+    limbs
+}
+
+fn square(limbs: &[u64]) -> Vec<u64> {
+    let mut result = vec![0; 2 * limbs.len()];
+
+    for (i, &left) in limbs.iter().enumerate() {
+        let mut carry: u128 = 0;
+
+        for (j, &right) in limbs.iter().enumerate() {
+            let total = result[i + j] as u128 + left as u128 * right as u128 + carry;
+            result[i + j] = total as u64;
+            carry = total >> 64;
+        }
+
+        result[i + limbs.len()] = carry as u64;
+    }
+
+    result
+}
+
+fn add(left: &[u64], right: &[u64]) -> Vec<u64> {
+    let length = left.len().max(right.len());
+    let mut result = Vec::with_capacity(length + 1);
+    let mut carry = 0;
+
+    for index in 0..length {
+        let total = *left.get(index).unwrap_or(&0) as u128
+            + *right.get(index).unwrap_or(&0) as u128
+            + carry;
+        result.push(total as u64);
+        carry = total >> 64;
+    }
+
+    if carry != 0 {
+        result.push(carry as u64);
+    }
+
+    result
+}
+
+fn shift_right(limbs: &[u64], bits: u64) -> Vec<u64> {
+    let limb_shift = (bits / 64) as usize;
+    let bit_shift = bits % 64;
+
+    if limb_shift >= limbs.len() {
+        return vec![0];
+    }
+
+    let shifted = &limbs[limb_shift..];
+
+    (0..shifted.len())
+        .map(|index| {
+            let low = shifted[index] >> bit_shift;
+            let high = match (bit_shift, shifted.get(index + 1)) {
+                (0, _) | (_, None) => 0,
+                (_, Some(&next)) => next << (64 - bit_shift),
+            };
+            low | high
+        })
+        .collect()
+}
+
+fn mask_low_bits(limbs: &[u64], bits: u64) -> Vec<u64> {
+    let mut masked: Vec<u64> = limbs
+        .iter()
+        .copied()
+        .take(bits.div_ceil(64) as usize)
+        .collect();
+
+    if let Some(last) = masked.get_mut((bits / 64) as usize) {
+        *last &= (1 << (bits % 64)) - 1;
+    }
+
+    masked
+}
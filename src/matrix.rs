@@ -0,0 +1,444 @@
+use crate::{BigInt, BigRational};
+
+use std::fmt::{self, Display};
+use std::ops::*;
+
+// row major, rows * cols entries
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Matrix<T = BigInt> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<T>,
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds!");
+        &self.entries[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds!");
+        &mut self.entries[row * self.cols + col]
+    }
+}
+
+impl<T> Display for Matrix<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            let entries: Vec<String> = self.row(row).iter().map(|e| e.to_string()).collect();
+            write!(f, "[{}]", entries.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.entries.into_iter().map(|e| -e).collect(),
+        }
+    }
+}
+
+impl<T> Add for Matrix<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn add(self, right: Matrix<T>) -> Self::Output {
+        assert!(
+            self.rows == right.rows && self.cols == right.cols,
+            "dimension mismatch!"
+        );
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .into_iter()
+                .zip(right.entries)
+                .map(|(left, right)| left + right)
+                .collect(),
+        }
+    }
+}
+
+impl<T> Sub for Matrix<T>
+where
+    T: Add<Output = T> + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn sub(self, right: Matrix<T>) -> Self::Output {
+        self + (-right)
+    }
+}
+
+impl<T> Mul for Matrix<T>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn mul(self, right: Matrix<T>) -> Self::Output {
+        assert!(self.cols == right.rows, "dimension mismatch!");
+
+        let mut result = Matrix::zeros(self.rows, right.cols);
+        for row in 0..self.rows {
+            for col in 0..right.cols {
+                result[(row, col)] = (0..self.cols).fold(T::default(), |sum, k| {
+                    sum + self[(row, k)].clone() * right[(k, col)].clone()
+                });
+            }
+        }
+
+        result
+    }
+}
+
+impl<T> AddAssign for Matrix<T>
+where
+    T: Clone + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T> SubAssign for Matrix<T>
+where
+    T: Clone + Add<Output = T> + Neg<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<T> MulAssign for Matrix<T>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl From<Matrix<BigInt>> for Matrix<BigRational> {
+    fn from(matrix: Matrix<BigInt>) -> Self {
+        Matrix {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            entries: matrix.entries.into_iter().map(BigRational::from).collect(),
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Creates a matrix from its rows, panics when the rows have different lengths.
+    pub fn new<U>(rows: Vec<Vec<U>>) -> Matrix<T>
+    where
+        U: Into<T>,
+    {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows of different lengths!"
+        );
+
+        Matrix {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().map(Into::into).collect(),
+        }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T>
+    where
+        T: Clone + Default,
+    {
+        Matrix {
+            rows,
+            cols,
+            entries: vec![T::default(); rows * cols],
+        }
+    }
+
+    pub fn identity(size: usize) -> Matrix<T>
+    where
+        T: Clone + Default + From<u8>,
+    {
+        let mut result = Matrix::zeros(size, size);
+        for i in 0..size {
+            result[(i, i)] = T::from(1);
+        }
+
+        result
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "index out of bounds!");
+        &self.entries[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries: (0..self.cols)
+                .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+                .map(|index| self[index].clone())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn to_rows(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        (0..self.rows).map(|row| self.row(row).to_vec()).collect()
+    }
+
+    /// Inverse of to_rows that keeps the column count when there are no rows.
+    pub(crate) fn from_rows(rows: Vec<Vec<T>>, cols: usize) -> Matrix<T> {
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows of different lengths!"
+        );
+
+        Matrix {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl Matrix<BigInt> {
+    /// Fraction-free Gaussian elimination (Bareiss), panics on a non-square matrix.
+    pub fn determinant(&self) -> BigInt {
+        assert!(self.is_square(), "matrix is not square!");
+
+        //empty matrix edgecase
+        if self.rows == 0 {
+            return BigInt::from(1);
+        }
+
+        let mut rows = self.to_rows();
+        let (rank, negated) = bareiss(&mut rows);
+
+        if rank < self.rows {
+            BigInt::default()
+        } else if negated {
+            -rows[self.rows - 1][self.cols - 1].clone()
+        } else {
+            rows[self.rows - 1][self.cols - 1].clone()
+        }
+    }
+
+    /// Fraction-free Gaussian elimination (Bareiss).
+    pub fn rank(&self) -> usize {
+        bareiss(&mut self.to_rows()).0
+    }
+
+    /// Transposed cofactor matrix, self * adjugate = determinant * identity.
+    /// Panics on a non-square matrix.
+    pub fn adjugate(&self) -> Matrix<BigInt> {
+        assert!(self.is_square(), "matrix is not square!");
+
+        let size = self.rows;
+        let determinant = self.determinant();
+
+        if determinant != 0 {
+            let inverse = self.inverse().unwrap();
+            return Matrix {
+                rows: size,
+                cols: size,
+                entries: inverse
+                    .entries
+                    .into_iter()
+                    .map(|e| (e * determinant.clone()).to_integer())
+                    .collect(),
+            };
+        }
+
+        //every cofactor vanishes below rank n - 1
+        let mut result = Matrix::zeros(size, size);
+        if size == 1 {
+            result[(0, 0)] = BigInt::from(1);
+        }
+        if size < 2 || self.rank() < size - 1 {
+            return result;
+        }
+
+        for row in 0..size {
+            for col in 0..size {
+                let minor = Matrix::new(
+                    (0..size)
+                        .filter(|&i| i != row)
+                        .map(|i| {
+                            (0..size)
+                                .filter(|&j| j != col)
+                                .map(|j| self[(i, j)].clone())
+                                .collect()
+                        })
+                        .collect::<Vec<Vec<BigInt>>>(),
+                );
+                let cofactor = minor.determinant();
+                result[(col, row)] = if (row + col) % 2 == 0 {
+                    cofactor
+                } else {
+                    -cofactor
+                };
+            }
+        }
+
+        result
+    }
+
+    /// Exact inverse, None when the matrix is singular. Panics on a non-square matrix.
+    pub fn inverse(&self) -> Option<Matrix<BigRational>> {
+        assert!(self.is_square(), "matrix is not square!");
+
+        let size = self.rows;
+        let mut rows = Matrix::<BigRational>::from(self.clone()).to_rows();
+        for (i, row) in rows.iter_mut().enumerate() {
+            row.extend((0..size).map(|j| BigRational::from(u8::from(i == j))));
+        }
+
+        if gauss_jordan(&mut rows, size).len() < size {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: size,
+            cols: size,
+            entries: rows
+                .into_iter()
+                .flat_map(|row| row[size..].to_vec())
+                .collect(),
+        })
+    }
+
+    /// One exact solution of self * x = rhs with the free variables set to zero,
+    /// None when the system is inconsistent. Panics when rhs doesn't have `rows` entries.
+    pub fn solve(&self, rhs: &[BigInt]) -> Option<Vec<BigRational>> {
+        assert!(rhs.len() == self.rows, "dimension mismatch!");
+
+        let mut rows = Matrix::<BigRational>::from(self.clone()).to_rows();
+        for (row, value) in rows.iter_mut().zip(rhs) {
+            row.push(BigRational::from(value));
+        }
+
+        let pivots = gauss_jordan(&mut rows, self.cols);
+
+        //a zero row with a non-zero right side
+        if rows[pivots.len()..].iter().any(|row| row[self.cols] != 0) {
+            return None;
+        }
+
+        let mut solution = vec![BigRational::default(); self.cols];
+        for (row, col) in pivots.into_iter().enumerate() {
+            solution[col] = rows[row][self.cols].clone();
+        }
+
+        Some(solution)
+    }
+}
+
+// row echelon form in place, every entry stays a minor of the original matrix so the
+// divisions are exact, returns the rank and whether an odd number of rows were swapped
+fn bareiss(rows: &mut [Vec<BigInt>]) -> (usize, bool) {
+    let cols = rows.first().map_or(0, |row| row.len());
+    let mut previous = BigInt::from(1);
+    let mut rank = 0;
+    let mut negated = false;
+
+    for col in 0..cols {
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row][col] != 0) else {
+            continue;
+        };
+        if pivot != rank {
+            rows.swap(pivot, rank);
+            negated = !negated;
+        }
+
+        for row in rank + 1..rows.len() {
+            for j in col + 1..cols {
+                rows[row][j] = (rows[rank][col].clone() * rows[row][j].clone()
+                    - rows[row][col].clone() * rows[rank][j].clone())
+                    / previous.clone();
+            }
+            rows[row][col] = BigInt::default();
+        }
+
+        previous = rows[rank][col].clone();
+        rank += 1;
+    }
+
+    (rank, negated)
+}
+
+// reduced row echelon form over the first `cols` columns, returns the pivot columns
+fn gauss_jordan(rows: &mut [Vec<BigRational>], cols: usize) -> Vec<usize> {
+    let mut pivots = vec![];
+
+    for col in 0..cols {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row][col] != 0) else {
+            continue;
+        };
+        rows.swap(pivot, rank);
+
+        let scale = rows[rank][col].recip();
+        for entry in rows[rank].iter_mut() {
+            *entry *= scale.clone();
+        }
+
+        for row in 0..rows.len() {
+            if row == rank || rows[row][col] == 0 {
+                continue;
+            }
+            let factor = rows[row][col].clone();
+            for j in col..rows[row].len() {
+                let subtrahend = factor.clone() * rows[rank][j].clone();
+                rows[row][j] -= subtrahend;
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    pivots
+}
@@ -0,0 +1,172 @@
+use crate::number_theory::{extended_gcd, rem_euclid};
+use crate::{BigInt, Matrix};
+
+impl Matrix<BigInt> {
+    /// (H, U) with U * self = H, U unimodular and H in row Hermite normal form:
+    /// echelon form with positive pivots and the entries above each pivot in [0, pivot).
+    pub fn hermite_normal_form(&self) -> (Matrix<BigInt>, Matrix<BigInt>) {
+        let mut h = self.to_rows();
+        let mut u = Matrix::<BigInt>::identity(self.rows()).to_rows();
+        let mut rank = 0;
+
+        for col in 0..self.cols() {
+            if rank == h.len() {
+                break;
+            }
+
+            //gcd of the column collects in the pivot row
+            for row in rank + 1..h.len() {
+                if h[row][col] == 0 {
+                    continue;
+                }
+
+                let (a, b) = (h[rank][col].clone(), h[row][col].clone());
+                let (gcd, x, y) = extended_gcd(&a, &b);
+                let transform = [x, y, -(b / gcd.clone()), a / gcd];
+                combine_rows(&mut h, rank, row, &transform);
+                combine_rows(&mut u, rank, row, &transform);
+            }
+
+            if h[rank][col] == 0 {
+                continue;
+            }
+            if h[rank][col] < 0 {
+                negate_row(&mut h, rank);
+                negate_row(&mut u, rank);
+            }
+
+            for row in 0..rank {
+                let pivot = h[rank][col].clone();
+                let quotient = (h[row][col].clone() - rem_euclid(&h[row][col], &pivot)) / pivot;
+                subtract_row(&mut h, row, rank, &quotient);
+                subtract_row(&mut u, row, rank, &quotient);
+            }
+
+            rank += 1;
+        }
+
+        (
+            Matrix::from_rows(h, self.cols()),
+            Matrix::from_rows(u, self.rows()),
+        )
+    }
+
+    /// (S, U, V) with U * self * V = S, U and V unimodular and S diagonal with
+    /// non-negative entries, each dividing the next.
+    pub fn smith_normal_form(&self) -> (Matrix<BigInt>, Matrix<BigInt>, Matrix<BigInt>) {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut s = self.to_rows();
+        let mut u = Matrix::<BigInt>::identity(rows).to_rows();
+        let mut v = Matrix::<BigInt>::identity(cols).to_rows();
+
+        for t in 0..rows.min(cols) {
+            loop {
+                //the smallest entry becomes the pivot, every pass makes it smaller
+                let Some((pivot_row, pivot_col)) = (t..rows)
+                    .flat_map(|i| (t..cols).map(move |j| (i, j)))
+                    .filter(|&(i, j)| s[i][j] != 0)
+                    .min_by(|&(a, b), &(c, d)| s[a][b].abs().partial_cmp(&s[c][d].abs()).unwrap())
+                else {
+                    return (
+                        Matrix::from_rows(s, cols),
+                        Matrix::from_rows(u, rows),
+                        Matrix::from_rows(v, cols),
+                    );
+                };
+
+                s.swap(t, pivot_row);
+                u.swap(t, pivot_row);
+                swap_cols(&mut s, t, pivot_col);
+                swap_cols(&mut v, t, pivot_col);
+
+                let pivot = s[t][t].clone();
+                for row in t + 1..rows {
+                    let quotient = s[row][t].clone() / pivot.clone();
+                    subtract_row(&mut s, row, t, &quotient);
+                    subtract_row(&mut u, row, t, &quotient);
+                }
+                for col in t + 1..cols {
+                    let quotient = s[t][col].clone() / pivot.clone();
+                    subtract_col(&mut s, col, t, &quotient);
+                    subtract_col(&mut v, col, t, &quotient);
+                }
+
+                if (t + 1..rows).any(|row| s[row][t] != 0)
+                    || (t + 1..cols).any(|col| s[t][col] != 0)
+                {
+                    continue;
+                }
+
+                //a remainder that isn't divisible by the pivot moves into the pivot row
+                let indivisible = (t + 1..rows)
+                    .find(|&row| (t + 1..cols).any(|col| rem_euclid(&s[row][col], &pivot) != 0));
+                match indivisible {
+                    Some(row) => {
+                        subtract_row(&mut s, t, row, &BigInt::from(-1));
+                        subtract_row(&mut u, t, row, &BigInt::from(-1));
+                    }
+                    None => break,
+                }
+            }
+
+            if s[t][t] < 0 {
+                negate_row(&mut s, t);
+                negate_row(&mut u, t);
+            }
+        }
+
+        (
+            Matrix::from_rows(s, cols),
+            Matrix::from_rows(u, rows),
+            Matrix::from_rows(v, cols),
+        )
+    }
+}
+
+// (first, second) = (x * first + y * second, z * first + w * second) with [x, y, z, w]
+fn combine_rows(rows: &mut [Vec<BigInt>], first: usize, second: usize, transform: &[BigInt; 4]) {
+    let [x, y, z, w] = transform;
+
+    for col in 0..rows[first].len() {
+        let (a, b) = (rows[first][col].clone(), rows[second][col].clone());
+        rows[first][col] = x.clone() * a.clone() + y.clone() * b.clone();
+        rows[second][col] = z.clone() * a + w.clone() * b;
+    }
+}
+
+// target -= quotient * source
+fn subtract_row(rows: &mut [Vec<BigInt>], target: usize, source: usize, quotient: &BigInt) {
+    //zero edgecase
+    if *quotient == 0 {
+        return;
+    }
+
+    for col in 0..rows[target].len() {
+        let subtrahend = quotient.clone() * rows[source][col].clone();
+        rows[target][col] -= subtrahend;
+    }
+}
+
+fn subtract_col(rows: &mut [Vec<BigInt>], target: usize, source: usize, quotient: &BigInt) {
+    //zero edgecase
+    if *quotient == 0 {
+        return;
+    }
+
+    for row in rows.iter_mut() {
+        let subtrahend = quotient.clone() * row[source].clone();
+        row[target] -= subtrahend;
+    }
+}
+
+fn negate_row(rows: &mut [Vec<BigInt>], row: usize) {
+    for entry in rows[row].iter_mut() {
+        *entry = -entry.clone();
+    }
+}
+
+fn swap_cols(rows: &mut [Vec<BigInt>], first: usize, second: usize) {
+    for row in rows.iter_mut() {
+        row.swap(first, second);
+    }
+}
@@ -0,0 +1,521 @@
+use crate::BigInt;
+
+use num_traits::Pow;
+
+use std::collections::HashMap;
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`.
+///
+/// Moduli don't have to be coprime. Congruences are merged pairwise up a
+/// balanced tree, there is no remainder tree. Returns the smallest non-negative
+/// solution together with the lcm of all moduli, or `None` when the system is
+/// inconsistent or some modulus isn't positive.
+pub fn crt(congruences: &[(BigInt, BigInt)]) -> Option<(BigInt, BigInt)> {
+    let mut level = congruences
+        .iter()
+        .map(|(residue, modulus)| {
+            if *modulus <= 0 {
+                return None;
+            }
+            Some((rem_euclid(residue, modulus), modulus.clone()))
+        })
+        .collect::<Option<Vec<(BigInt, BigInt)>>>()?;
+
+    //empty system edgecase
+    if level.is_empty() {
+        return Some((BigInt::default(), 1.into()));
+    }
+
+    //merge pairwise level by level so both sides of a merge stay of similar size
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+        let mut congruences = level.into_iter();
+
+        while let Some(left) = congruences.next() {
+            next_level.push(match congruences.next() {
+                Some(right) => merge_congruences(left, right)?,
+                None => left,
+            });
+        }
+
+        level = next_level;
+    }
+
+    level.pop()
+}
+
+fn merge_congruences(
+    (left_residue, left_modulus): (BigInt, BigInt),
+    (right_residue, right_modulus): (BigInt, BigInt),
+) -> Option<(BigInt, BigInt)> {
+    let (gcd, left_coefficient, _) = extended_gcd(&left_modulus, &right_modulus);
+    let difference = right_residue - left_residue.clone();
+
+    //residues have to agree modulo gcd
+    if rem_euclid(&difference, &gcd) != 0 {
+        return None;
+    }
+
+    let reduced_right = right_modulus / gcd.clone();
+    let step = rem_euclid(&(difference / gcd * left_coefficient), &reduced_right);
+
+    let residue = left_residue + left_modulus.clone() * step;
+    let modulus = left_modulus * reduced_right;
+
+    Some((residue, modulus))
+}
+
+pub(crate) fn rem_euclid(left: &BigInt, modulus: &BigInt) -> BigInt {
+    if *left == 0 {
+        return BigInt::default();
+    }
+
+    let modulus = modulus.abs();
+    let (_, remainder) = BigInt::divide_with_remainder(left.abs(), modulus.clone());
+
+    if remainder == 0 || left.positive {
+        remainder
+    } else {
+        modulus - remainder
+    }
+}
+
+// returns (gcd, x, y) such that left * x + right * y = gcd
+pub(crate) fn extended_gcd(left: &BigInt, right: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut old_remainder, mut remainder) = (left.abs(), right.abs());
+    let (mut old_x, mut x): (BigInt, BigInt) = (1.into(), 0.into());
+    let (mut old_y, mut y): (BigInt, BigInt) = (0.into(), 1.into());
+
+    while remainder != 0 {
+        let quotient = old_remainder.clone() / remainder.clone();
+
+        let new_remainder = old_remainder - quotient.clone() * remainder.clone();
+        old_remainder = std::mem::replace(&mut remainder, new_remainder);
+
+        let new_x = old_x - quotient.clone() * x.clone();
+        old_x = std::mem::replace(&mut x, new_x);
+
+        let new_y = old_y - quotient * y.clone();
+        old_y = std::mem::replace(&mut y, new_y);
+    }
+
+    if !left.positive {
+        old_x = -old_x;
+    }
+    if !right.positive {
+        old_y = -old_y;
+    }
+
+    (old_remainder, old_x, old_y)
+}
+
+/// Finds `x` such that `g^x ≡ h (mod p)` for a prime `p`.
+///
+/// The order of `g` is factored and the problem is split by Pohlig–Hellman
+/// into prime order subgroups, which are solved by baby-step giant-step when
+/// small and by Pollard rho otherwise. Returns the smallest non-negative `x`,
+/// or `None` when `h` isn't a power of `g`. Panics when `p` isn't prime.
+pub fn discrete_log(g: &BigInt, h: &BigInt, p: &BigInt) -> Option<BigInt> {
+    assert!(is_probable_prime(p), "modulus has to be prime!");
+
+    let g = rem_euclid(g, p);
+    let h = rem_euclid(h, p);
+
+    if h == 1 {
+        return Some(BigInt::default());
+    }
+    //zero is only reachable as 0^x
+    if g == 0 || h == 0 {
+        return if g == h { Some(1.into()) } else { None };
+    }
+
+    //order of g divides the order of the group
+    let mut order = p.clone() - 1;
+    for (prime, exponent) in factorize(&order) {
+        for _ in 0..exponent {
+            let reduced = order.clone() / prime.clone();
+            if mod_pow(&g, &reduced, p) != 1 {
+                break;
+            }
+            order = reduced;
+        }
+    }
+
+    //h has to lie in the subgroup generated by g
+    if mod_pow(&h, &order, p) != 1 {
+        return None;
+    }
+
+    let mut congruences = vec![];
+
+    for (prime, exponent) in factorize(&order) {
+        let prime_power = prime.clone().pow(exponent);
+        let cofactor = order.clone() / prime_power.clone();
+
+        let sub_generator = mod_pow(&g, &cofactor, p);
+        let sub_target = mod_pow(&h, &cofactor, p);
+        let sub_generator_inverse = mod_inverse(&sub_generator, p)?;
+
+        //generator of the subgroup of order prime
+        let gamma = mod_pow(&sub_generator, &prime.clone().pow(exponent - 1), p);
+
+        let mut log = BigInt::default();
+        let mut digit_weight: BigInt = 1.into();
+
+        //lift the log digit by digit in base prime
+        for digit in 0..exponent {
+            let shifted = mod_mul(&mod_pow(&sub_generator_inverse, &log, p), &sub_target, p);
+            let target = mod_pow(&shifted, &prime.clone().pow(exponent - 1 - digit), p);
+
+            let digit_log = prime_order_log(&gamma, &target, &prime, p)?;
+
+            log += digit_log * digit_weight.clone();
+            digit_weight *= prime.clone();
+        }
+
+        congruences.push((log, prime_power));
+    }
+
+    let (log, _) = crt(&congruences)?;
+
+    if mod_pow(&g, &log, p) != h {
+        return None;
+    }
+
+    Some(log)
+}
+
+const BABY_STEP_GIANT_STEP_LIMIT: u64 = 1 << 24;
+
+fn prime_order_log(
+    generator: &BigInt,
+    target: &BigInt,
+    order: &BigInt,
+    modulus: &BigInt,
+) -> Option<BigInt> {
+    if *order <= BABY_STEP_GIANT_STEP_LIMIT {
+        baby_step_giant_step(generator, target, order, modulus)
+    } else {
+        pollard_rho_log(generator, target, order, modulus)
+    }
+}
+
+pub(crate) fn baby_step_giant_step(
+    generator: &BigInt,
+    target: &BigInt,
+    order: &BigInt,
+    modulus: &BigInt,
+) -> Option<BigInt> {
+    let step_count = isqrt(order) + 1;
+
+    let mut baby_steps = HashMap::new();
+    let mut power: BigInt = 1.into();
+    let mut exponent = BigInt::default();
+
+    while exponent < step_count {
+        baby_steps.entry(power.clone()).or_insert(exponent.clone());
+        power = mod_mul(&power, generator, modulus);
+        exponent += 1;
+    }
+
+    //generator^(-step_count)
+    let giant_step = mod_inverse(&power, modulus)?;
+
+    let mut current = rem_euclid(target, modulus);
+    let mut giant = BigInt::default();
+
+    while giant < step_count {
+        if let Some(baby) = baby_steps.get(&current) {
+            return Some(rem_euclid(
+                &(giant * step_count.clone() + baby.clone()),
+                order,
+            ));
+        }
+
+        current = mod_mul(&current, &giant_step, modulus);
+        giant += 1;
+    }
+
+    None
+}
+
+pub(crate) fn pollard_rho_log(
+    generator: &BigInt,
+    target: &BigInt,
+    order: &BigInt,
+    modulus: &BigInt,
+) -> Option<BigInt> {
+    let three: BigInt = 3.into();
+
+    //walk through generator^a * target^b, the class of the value picks the step
+    let step = |(value, a, b): (BigInt, BigInt, BigInt)| {
+        let class = rem_euclid(&value, &three);
+
+        if class == 0 {
+            (
+                mod_mul(&value, &value, modulus),
+                rem_euclid(&(a * 2), order),
+                rem_euclid(&(b * 2), order),
+            )
+        } else if class == 1 {
+            (
+                mod_mul(&value, generator, modulus),
+                rem_euclid(&(a + 1), order),
+                b,
+            )
+        } else {
+            (
+                mod_mul(&value, target, modulus),
+                a,
+                rem_euclid(&(b + 1), order),
+            )
+        }
+    };
+
+    for attempt in 1..=POLLARD_RHO_ATTEMPTS {
+        let start_exponent = rem_euclid(&attempt.into(), order);
+        let start = (
+            mod_mul(
+                &mod_pow(generator, &start_exponent, modulus),
+                target,
+                modulus,
+            ),
+            start_exponent,
+            BigInt::from(1),
+        );
+
+        let mut tortoise = step(start.clone());
+        let mut hare = step(step(start));
+
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+
+        //generator^(a1 - a2) = target^(b2 - b1)
+        let exponent_difference = rem_euclid(&(tortoise.1 - hare.1), order);
+        let target_difference = rem_euclid(&(hare.2 - tortoise.2), order);
+
+        let Some(inverse) = mod_inverse(&target_difference, order) else {
+            continue;
+        };
+
+        let log = rem_euclid(&(exponent_difference * inverse), order);
+
+        if mod_pow(generator, &log, modulus) == rem_euclid(target, modulus) {
+            return Some(log);
+        }
+    }
+
+    None
+}
+
+const POLLARD_RHO_ATTEMPTS: u32 = 32;
+
+pub(crate) fn mod_mul(left: &BigInt, right: &BigInt, modulus: &BigInt) -> BigInt {
+    rem_euclid(&(left.clone() * right.clone()), modulus)
+}
+
+pub(crate) fn mod_pow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+    if *modulus == 1 {
+        return BigInt::default();
+    }
+
+    let base = rem_euclid(base, modulus);
+    let mut result: BigInt = 1.into();
+
+    //square and multiply from the most significant bit
+    for bit in exponent.to_binary().1 {
+        result = mod_mul(&result, &result, modulus);
+        if bit {
+            result = mod_mul(&result, &base, modulus);
+        }
+    }
+
+    result
+}
+
+pub(crate) fn isqrt(number: &BigInt) -> BigInt {
+    if *number <= 0 {
+        return BigInt::default();
+    }
+
+    //10^ceil(digits / 2) is always above the root
+    let mut root = BigInt::from(10).pow(number.magnitude.numbers.len().div_ceil(2) as u64);
+
+    loop {
+        let next = (root.clone() + number.clone() / root.clone()) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+pub(crate) fn gcd(left: &BigInt, right: &BigInt) -> BigInt {
+    let mut left = left.abs();
+    let mut right = right.abs();
+
+    while right != 0 {
+        let remainder = rem_euclid(&left, &right);
+        left = right;
+        right = remainder;
+    }
+
+    left
+}
+
+pub(crate) fn mod_inverse(number: &BigInt, modulus: &BigInt) -> Option<BigInt> {
+    let (gcd, x, _) = extended_gcd(&rem_euclid(number, modulus), modulus);
+
+    if gcd != 1 {
+        return None;
+    }
+
+    Some(rem_euclid(&x, modulus))
+}
+
+const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+// Miller–Rabin with the first 12 primes as bases, deterministic below 3.3 * 10^24
+pub(crate) fn is_probable_prime(number: &BigInt) -> bool {
+    if *number < 2 {
+        return false;
+    }
+
+    for prime in SMALL_PRIMES {
+        if *number == prime {
+            return true;
+        }
+        if rem_euclid(number, &prime.into()) == 0 {
+            return false;
+        }
+    }
+
+    let number_minus_one = number.clone() - 1;
+
+    //number - 1 = odd * 2^twos
+    let mut odd = number_minus_one.clone();
+    let mut twos = 0;
+    while odd.is_even() {
+        odd /= 2;
+        twos += 1;
+    }
+
+    'witness: for prime in &SMALL_PRIMES[..12] {
+        let mut x = mod_pow(&(*prime).into(), &odd, number);
+
+        if x == 1 || x == number_minus_one {
+            continue;
+        }
+
+        for _ in 1..twos {
+            x = mod_mul(&x, &x, number);
+            if x == number_minus_one {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+// returns prime factors with their exponents in ascending order
+pub(crate) fn factorize(number: &BigInt) -> Vec<(BigInt, u32)> {
+    let mut remaining = number.abs();
+    let mut primes = vec![];
+
+    for prime in SMALL_PRIMES {
+        while remaining != 1 && rem_euclid(&remaining, &prime.into()) == 0 {
+            remaining /= prime;
+            primes.push(BigInt::from(prime));
+        }
+    }
+
+    let mut composites = vec![];
+    if remaining > 1 {
+        composites.push(remaining);
+    }
+
+    while let Some(composite) = composites.pop() {
+        if is_probable_prime(&composite) {
+            primes.push(composite);
+        } else {
+            let divisor = pollard_rho_factor(&composite);
+            composites.push(composite / divisor.clone());
+            composites.push(divisor);
+        }
+    }
+
+    primes.sort_by(|left, right| left.partial_cmp(right).unwrap());
+
+    let mut factors: Vec<(BigInt, u32)> = vec![];
+    for prime in primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => factors.push((prime, 1)),
+        }
+    }
+
+    factors
+}
+
+// finds a non-trivial divisor of an odd composite number, Brent's variant of Pollard rho
+fn pollard_rho_factor(number: &BigInt) -> BigInt {
+    let mut constant: BigInt = 1.into();
+
+    loop {
+        let next = |x: &BigInt| rem_euclid(&(x.clone() * x.clone() + constant.clone()), number);
+
+        let mut hare: BigInt = 2.into();
+        let mut tortoise = hare.clone();
+        let mut saved_hare = hare.clone();
+        let mut product: BigInt = 1.into();
+        let mut divisor: BigInt = 1.into();
+        let mut cycle_length = 1;
+
+        while divisor == 1 {
+            tortoise = hare.clone();
+            for _ in 0..cycle_length {
+                hare = next(&hare);
+            }
+
+            //one gcd per batch of differences
+            let mut steps = 0;
+            while steps < cycle_length && divisor == 1 {
+                saved_hare = hare.clone();
+                for _ in 0..POLLARD_RHO_BATCH.min(cycle_length - steps) {
+                    hare = next(&hare);
+                    product = mod_mul(&product, &(tortoise.clone() - hare.clone()), number);
+                }
+                divisor = gcd(&product, number);
+                steps += POLLARD_RHO_BATCH;
+            }
+
+            cycle_length *= 2;
+        }
+
+        //the batch overshot, redo it one step at a time
+        if divisor == *number {
+            loop {
+                saved_hare = next(&saved_hare);
+                divisor = gcd(&(tortoise.clone() - saved_hare.clone()), number);
+                if divisor != 1 {
+                    break;
+                }
+            }
+        }
+
+        if divisor != *number {
+            return divisor;
+        }
+
+        constant += 1;
+    }
+}
+
+const POLLARD_RHO_BATCH: u32 = 64;
@@ -0,0 +1,438 @@
+use crate::BigInt;
+use crate::number_theory::gcd;
+
+use num_traits::Pow;
+
+use std::fmt::{self, Display};
+use std::ops::*;
+
+// below this many coefficients schoolbook multiplication is faster
+const KARATSUBA_THRESHOLD: usize = 16;
+
+// coefficients from the constant term up, without trailing zeros
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Polynomial {
+    coefficients: Vec<BigInt>,
+}
+
+impl From<BigInt> for Polynomial {
+    fn from(constant: BigInt) -> Self {
+        Polynomial::new(vec![constant])
+    }
+}
+
+impl From<&BigInt> for Polynomial {
+    fn from(constant: &BigInt) -> Self {
+        Polynomial::from(constant.clone())
+    }
+}
+
+macro_rules! from_primitive_polynomial {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for Polynomial{
+                fn from(original_number: $t) -> Self {
+                    Polynomial::from(BigInt::from(original_number))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_polynomial!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if *coefficient == 0 {
+                continue;
+            }
+
+            if output.is_empty() {
+                if !coefficient.positive {
+                    output.push('-');
+                }
+            } else {
+                output.push_str(if coefficient.positive { " + " } else { " - " });
+            }
+
+            //x instead of 1x
+            if power == 0 || coefficient.abs() != 1 {
+                output.push_str(&coefficient.abs().to_string());
+            }
+
+            match power {
+                0 => {}
+                1 => output.push('x'),
+                _ => output.push_str(&format!("x^{power}")),
+            }
+        }
+
+        //zero edgecase
+        if output.is_empty() {
+            output.push('0');
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefficients: self.coefficients.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<T> Add<T> for Polynomial
+where
+    T: Into<Polynomial>,
+{
+    type Output = Polynomial;
+    fn add(self, right: T) -> Self::Output {
+        Polynomial::new(add_coefficients(
+            &self.coefficients,
+            &right.into().coefficients,
+        ))
+    }
+}
+
+impl<T> Sub<T> for Polynomial
+where
+    T: Into<Polynomial>,
+{
+    type Output = Polynomial;
+    fn sub(self, right: T) -> Self::Output {
+        self + (-right.into())
+    }
+}
+
+impl<T> Mul<T> for Polynomial
+where
+    T: Into<Polynomial>,
+{
+    type Output = Polynomial;
+    fn mul(self, right: T) -> Self::Output {
+        Polynomial::new(multiply_coefficients(
+            &self.coefficients,
+            &right.into().coefficients,
+        ))
+    }
+}
+
+// panics on a negative exponent
+impl<T> Pow<T> for Polynomial
+where
+    T: Into<BigInt>,
+{
+    type Output = Polynomial;
+    fn pow(self, right: T) -> Self::Output {
+        let right = right.into();
+        assert!(right >= 0, "negative exponent!");
+
+        let mut result = Polynomial::from(1);
+
+        //square and multiply from the most significant bit
+        for bit in right.to_binary().1 {
+            result = result.clone() * result;
+            if bit {
+                result *= self.clone();
+            }
+        }
+
+        result
+    }
+}
+
+assign_trait!(Polynomial, AddAssign, add_assign, add);
+assign_trait!(Polynomial, SubAssign, sub_assign, sub);
+assign_trait!(Polynomial, MulAssign, mul_assign, mul);
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, constant term first.
+    pub fn new<T>(coefficients: Vec<T>) -> Polynomial
+    where
+        T: Into<BigInt>,
+    {
+        let mut coefficients: Vec<BigInt> = coefficients.into_iter().map(Into::into).collect();
+        while coefficients.last().is_some_and(|c| *c == 0) {
+            coefficients.pop();
+        }
+
+        Polynomial { coefficients }
+    }
+
+    /// coefficient * x^degree
+    pub fn monomial<T>(coefficient: T, degree: usize) -> Polynomial
+    where
+        T: Into<BigInt>,
+    {
+        let mut coefficients = vec![BigInt::default(); degree];
+        coefficients.push(coefficient.into());
+
+        Polynomial::new(coefficients)
+    }
+
+    /// Coefficients from the constant term up, empty for the zero polynomial.
+    pub fn coefficients(&self) -> &[BigInt] {
+        &self.coefficients
+    }
+
+    /// None for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Zero for the zero polynomial.
+    pub fn leading_coefficient(&self) -> BigInt {
+        self.coefficients.last().cloned().unwrap_or_default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Evaluates with Horner's rule.
+    pub fn evaluate<T>(&self, x: T) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        let x = x.into();
+
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigInt::default(), |result, c| {
+                result * x.clone() + c.clone()
+            })
+    }
+
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, c)| c.clone() * power as u64)
+                .collect(),
+        )
+    }
+
+    /// Gcd of the coefficients with the sign of the leading coefficient, zero for the zero polynomial.
+    pub fn content(&self) -> BigInt {
+        let content = self
+            .coefficients
+            .iter()
+            .fold(BigInt::default(), |result, c| gcd(&result, c));
+
+        if self.leading_coefficient() < 0 {
+            -content
+        } else {
+            content
+        }
+    }
+
+    /// self / content, the leading coefficient is positive.
+    pub fn primitive_part(&self) -> Polynomial {
+        //zero edgecase
+        if self.is_zero() {
+            return Polynomial::default();
+        }
+
+        let content = self.content();
+
+        Polynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|c| c.clone() / content.clone())
+                .collect(),
+        }
+    }
+
+    /// (q, r) with lc(divisor)^(deg self - deg divisor + 1) * self = q * divisor + r
+    /// and deg r < deg divisor, panics on a zero divisor.
+    pub fn pseudo_div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        if divisor.is_zero() {
+            panic!("division by zero!");
+        }
+
+        let divisor_degree = divisor.coefficients.len() - 1;
+        if self.coefficients.len() <= divisor_degree {
+            return (Polynomial::default(), self.clone());
+        }
+
+        let leading = divisor.leading_coefficient();
+        let mut steps = self.coefficients.len() - divisor_degree;
+        let mut quotient = Polynomial::default();
+        let mut remainder = self.clone();
+
+        while remainder.coefficients.len() > divisor_degree {
+            let term = Polynomial::monomial(
+                remainder.leading_coefficient(),
+                remainder.coefficients.len() - 1 - divisor_degree,
+            );
+            quotient = quotient * leading.clone() + term.clone();
+            remainder = remainder * leading.clone() - term * divisor.clone();
+            steps -= 1;
+        }
+
+        //the remainder can lose more than one degree per step
+        let scale = leading.pow(steps as u64);
+
+        (quotient * scale.clone(), remainder * scale)
+    }
+
+    /// Pseudo-remainder, see `pseudo_div_rem`.
+    pub fn pseudo_rem(&self, divisor: &Polynomial) -> Polynomial {
+        self.pseudo_div_rem(divisor).1
+    }
+
+    /// Greatest common divisor with a positive leading coefficient, subresultant remainder sequence.
+    pub fn gcd(&self, other: &Polynomial) -> Polynomial {
+        //zero edgecases
+        if other.is_zero() {
+            return self.primitive_part() * self.content().abs();
+        }
+        if self.is_zero() {
+            return other.gcd(self);
+        }
+
+        let (mut a, mut b) = if self.coefficients.len() >= other.coefficients.len() {
+            (self.primitive_part(), other.primitive_part())
+        } else {
+            (other.primitive_part(), self.primitive_part())
+        };
+        let content = gcd(&self.content(), &other.content());
+
+        let mut g = BigInt::from(1);
+        let mut h = BigInt::from(1);
+
+        loop {
+            let delta = (a.coefficients.len() - b.coefficients.len()) as u64;
+            let remainder = a.pseudo_rem(&b);
+
+            if remainder.is_zero() {
+                break;
+            }
+            if remainder.coefficients.len() == 1 {
+                b = Polynomial::from(1);
+                break;
+            }
+
+            //the divisor is exact, it keeps the coefficients from growing exponentially
+            let divisor = g.clone() * h.clone().pow(delta);
+            a = b;
+            b = Polynomial {
+                coefficients: remainder
+                    .coefficients
+                    .into_iter()
+                    .map(|c| c / divisor.clone())
+                    .collect(),
+            };
+
+            g = a.leading_coefficient();
+            h = if delta == 0 {
+                h
+            } else {
+                g.clone().pow(delta) / h.clone().pow(delta - 1)
+            };
+        }
+
+        b.primitive_part() * content
+    }
+}
+
+fn add_coefficients(left: &[BigInt], right: &[BigInt]) -> Vec<BigInt> {
+    let (long, short) = if left.len() >= right.len() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    let mut result = long.to_vec();
+    for (index, c) in short.iter().enumerate() {
+        result[index] += c.clone();
+    }
+
+    result
+}
+
+fn subtract_coefficients(left: &[BigInt], right: &[BigInt]) -> Vec<BigInt> {
+    let mut result = left.to_vec();
+    result.resize(left.len().max(right.len()), BigInt::default());
+    for (index, c) in right.iter().enumerate() {
+        result[index] -= c.clone();
+    }
+
+    result
+}
+
+// adds addend * x^offset into result
+fn add_coefficients_at(result: &mut Vec<BigInt>, addend: &[BigInt], offset: usize) {
+    if result.len() < addend.len() + offset {
+        result.resize(addend.len() + offset, BigInt::default());
+    }
+    for (index, c) in addend.iter().enumerate() {
+        result[index + offset] += c.clone();
+    }
+}
+
+fn multiply_coefficients(left: &[BigInt], right: &[BigInt]) -> Vec<BigInt> {
+    //zero edgecase
+    if left.is_empty() || right.is_empty() {
+        return vec![];
+    }
+
+    if left.len().min(right.len()) < KARATSUBA_THRESHOLD {
+        let mut result = vec![BigInt::default(); left.len() + right.len() - 1];
+        for (i, a) in left.iter().enumerate() {
+            for (j, b) in right.iter().enumerate() {
+                result[i + j] += a.clone() * b.clone();
+            }
+        }
+        return result;
+    }
+
+    let half = left.len().max(right.len()) / 2;
+
+    //only the longer factor gets split
+    if left.len() <= half || right.len() <= half {
+        let (long, short) = if left.len() > right.len() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let mut result = multiply_coefficients(&long[..half], short);
+        add_coefficients_at(
+            &mut result,
+            &multiply_coefficients(&long[half..], short),
+            half,
+        );
+        return result;
+    }
+
+    let (left_low, left_high) = left.split_at(half);
+    let (right_low, right_high) = right.split_at(half);
+
+    let low = multiply_coefficients(left_low, right_low);
+    let high = multiply_coefficients(left_high, right_high);
+    let middle = multiply_coefficients(
+        &add_coefficients(left_low, left_high),
+        &add_coefficients(right_low, right_high),
+    );
+    let middle = subtract_coefficients(&subtract_coefficients(&middle, &low), &high);
+
+    let mut result = low;
+    add_coefficients_at(&mut result, &middle, half);
+    add_coefficients_at(&mut result, &high, 2 * half);
+    result.truncate(left.len() + right.len() - 1);
+
+    result
+}
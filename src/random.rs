@@ -0,0 +1,95 @@
+use crate::number_theory::is_probable_prime;
+use crate::{BigInt, BigUint};
+
+use num_traits::Pow;
+use rand::RngCore;
+
+/// Uniformly distributed random `BigInt`s from any `RngCore`.
+pub trait RandBigInt {
+    /// Random number in `0..2^bits`.
+    fn gen_biguint(&mut self, bits: u64) -> BigUint;
+
+    /// Random number with magnitude below `2^bits` and random sign.
+    fn gen_bigint(&mut self, bits: u64) -> BigInt;
+
+    /// Random number in `0..bound`, panics if `bound` is zero.
+    fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint;
+
+    /// Random number in `low..high`, panics if the range is empty.
+    fn gen_bigint_range(&mut self, low: &BigInt, high: &BigInt) -> BigInt;
+
+    /// Random probable prime with exactly `bits` bits, panics if `bits < 2`.
+    fn gen_prime(&mut self, bits: u64) -> BigInt;
+}
+
+impl<R: RngCore + ?Sized> RandBigInt for R {
+    fn gen_biguint(&mut self, bits: u64) -> BigUint {
+        let mut result = BigUint::default();
+        let mut remaining = bits;
+
+        while remaining > 0 {
+            let word_bits = remaining.min(64);
+            let word = self.next_u64() >> (64 - word_bits);
+
+            result = result * (1_u128 << word_bits) + word;
+            remaining -= word_bits;
+        }
+
+        result
+    }
+
+    fn gen_bigint(&mut self, bits: u64) -> BigInt {
+        loop {
+            let magnitude = BigInt::from(self.gen_biguint(bits));
+            let negative = self.next_u32() & 1 == 1;
+
+            //negative zero would make zero twice as likely
+            if negative && magnitude == 0 {
+                continue;
+            }
+
+            return if negative { -magnitude } else { magnitude };
+        }
+    }
+
+    fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint {
+        assert!(*bound > 0_u8, "bound has to be positive");
+
+        let bits = bound.to_binary().len() as u64;
+
+        //rejection sampling, every try succeeds with probability above one half
+        loop {
+            let candidate = self.gen_biguint(bits);
+            if candidate < *bound {
+                return candidate;
+            }
+        }
+    }
+
+    fn gen_bigint_range(&mut self, low: &BigInt, high: &BigInt) -> BigInt {
+        assert!(low < high, "range is empty");
+
+        let width = BigUint::try_from(high.clone() - low.clone()).unwrap();
+
+        low.clone() + self.gen_biguint_below(&width)
+    }
+
+    fn gen_prime(&mut self, bits: u64) -> BigInt {
+        assert!(bits >= 2, "there are no primes with less than 2 bits");
+
+        let top_bit = BigInt::from(2).pow(bits - 1);
+
+        loop {
+            let mut candidate = BigInt::from(self.gen_biguint(bits - 1)) + top_bit.clone();
+
+            //only 2 is an even prime
+            if candidate.is_even() && bits > 2 {
+                candidate += 1;
+            }
+
+            if is_probable_prime(&candidate) {
+                return candidate;
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::number_theory::gcd;
+use crate::{BigInt, BigIntError};
+
+use num_traits::Pow;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::*;
+use std::str::FromStr;
+
+// always kept in lowest terms with a positive denominator
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BigRational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Default for BigRational {
+    fn default() -> Self {
+        BigRational {
+            numerator: BigInt::default(),
+            denominator: BigInt::from(1),
+        }
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = BigIntError;
+    fn from_str(string_of_numbers: &str) -> Result<Self, Self::Err> {
+        //fraction "3/4"
+        if let Some((numerator, denominator)) = string_of_numbers.split_once('/') {
+            let numerator = BigInt::from_str(numerator)?;
+            let denominator = BigInt::from_str(denominator)?;
+
+            if denominator == 0 {
+                return Err(BigIntError::DivisionByZero);
+            }
+
+            return Ok(BigRational::new(numerator, denominator));
+        }
+
+        //decimal "-1.25"
+        if let Some((integer, fraction)) = string_of_numbers.split_once('.') {
+            let positive = !integer.starts_with('-');
+            let integer = &integer[!positive as usize..];
+
+            if integer.is_empty() && fraction.is_empty()
+                || !integer.chars().all(|char| char.is_ascii_digit())
+                || !fraction.chars().all(|char| char.is_ascii_digit())
+            {
+                return Err(BigIntError::NaN);
+            }
+
+            let mut numerator = BigInt::from_str(&format!("{integer}{fraction}"))?;
+            if !positive {
+                numerator = -numerator;
+            }
+
+            return Ok(BigRational::new(
+                numerator,
+                BigInt::from(10).pow(fraction.len() as u64),
+            ));
+        }
+
+        Ok(BigRational::from(BigInt::from_str(string_of_numbers)?))
+    }
+}
+
+impl From<BigInt> for BigRational {
+    fn from(numerator: BigInt) -> Self {
+        BigRational {
+            numerator,
+            denominator: BigInt::from(1),
+        }
+    }
+}
+
+impl From<&BigInt> for BigRational {
+    fn from(numerator: &BigInt) -> Self {
+        BigRational::from(numerator.clone())
+    }
+}
+
+macro_rules! from_primitive_rational {
+    ($($t:ty),*)=>{
+        $(
+            impl From<$t> for BigRational{
+                fn from(original_number: $t) -> Self {
+                    BigRational::from(BigInt::from(original_number))
+                }
+            }
+        )*
+    }
+}
+
+from_primitive_rational!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl Display for BigRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        if f.sign_plus() && self.numerator.positive {
+            output.push('+');
+        }
+
+        output.push_str(&self.numerator.to_string());
+
+        if self.denominator != 1 {
+            output.push('/');
+            output.push_str(&self.denominator.to_string());
+        }
+
+        BigInt::add_alignment(&mut output, f);
+
+        write!(f, "{output}")
+    }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigRational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = self.numerator.clone() * other.denominator.clone();
+        let right = other.numerator.clone() * self.denominator.clone();
+
+        left.partial_cmp(&right).unwrap()
+    }
+}
+
+macro_rules! compare_with_primitive {
+    ($($t:ty),*)=>{
+        $(
+            impl PartialEq<$t> for BigRational {
+                fn eq(&self, other: &$t) -> bool {
+                    self.denominator == 1 && self.numerator == *other
+                }
+            }
+
+            impl PartialOrd<$t> for BigRational {
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    Some(self.cmp(&BigRational::from(*other)))
+                }
+            }
+        )*
+    }
+}
+
+compare_with_primitive!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl Neg for BigRational {
+    type Output = BigRational;
+    fn neg(self) -> Self::Output {
+        BigRational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl<T> Add<T> for BigRational
+where
+    T: Into<BigRational>,
+{
+    type Output = BigRational;
+    fn add(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        BigRational::new(
+            self.numerator * right.denominator.clone() + right.numerator * self.denominator.clone(),
+            self.denominator * right.denominator,
+        )
+    }
+}
+
+impl<T> Sub<T> for BigRational
+where
+    T: Into<BigRational>,
+{
+    type Output = BigRational;
+    fn sub(self, right: T) -> Self::Output {
+        self + (-right.into())
+    }
+}
+
+impl<T> Mul<T> for BigRational
+where
+    T: Into<BigRational>,
+{
+    type Output = BigRational;
+    fn mul(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        BigRational::new(
+            self.numerator * right.numerator,
+            self.denominator * right.denominator,
+        )
+    }
+}
+
+impl<T> Div<T> for BigRational
+where
+    T: Into<BigRational>,
+{
+    type Output = BigRational;
+    fn div(self, right: T) -> Self::Output {
+        self.mul(right.into().recip())
+    }
+}
+
+impl<T> Pow<T> for BigRational
+where
+    T: Into<BigInt>,
+{
+    type Output = BigRational;
+    fn pow(self, right: T) -> Self::Output {
+        let right = right.into();
+
+        //negative exponent edgecase
+        if right < 0 {
+            return self.recip().pow(-right);
+        }
+
+        BigRational {
+            numerator: self.numerator.pow(right.clone()),
+            denominator: self.denominator.pow(right),
+        }
+    }
+}
+
+assign_trait!(BigRational, AddAssign, add_assign, add);
+assign_trait!(BigRational, SubAssign, sub_assign, sub);
+assign_trait!(BigRational, MulAssign, mul_assign, mul);
+assign_trait!(BigRational, DivAssign, div_assign, div);
+
+impl BigRational {
+    /// Creates `numerator / denominator` reduced to lowest terms, panics on a zero denominator.
+    pub fn new<T, U>(numerator: T, denominator: U) -> BigRational
+    where
+        T: Into<BigInt>,
+        U: Into<BigInt>,
+    {
+        let mut numerator = numerator.into();
+        let mut denominator = denominator.into();
+
+        if denominator == 0 {
+            panic!("division by zero!");
+        }
+
+        if !denominator.positive {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+
+        let divisor = gcd(&numerator, &denominator);
+        if divisor != 1 {
+            numerator /= divisor.clone();
+            denominator /= divisor;
+        }
+
+        BigRational {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn abs(&self) -> BigRational {
+        BigRational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// Returns `1 / self`, panics on zero.
+    pub fn recip(&self) -> BigRational {
+        BigRational::new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    /// Integer part rounded toward zero.
+    pub fn to_integer(&self) -> BigInt {
+        self.numerator.clone() / self.denominator.clone()
+    }
+
+    pub fn trunc(&self) -> BigRational {
+        BigRational::from(self.to_integer())
+    }
+
+    pub fn floor(&self) -> BigRational {
+        let (quotient, remainder) =
+            BigInt::divide_with_remainder(self.numerator.clone(), self.denominator.clone());
+
+        if remainder < 0 {
+            return BigRational::from(quotient - 1);
+        }
+
+        BigRational::from(quotient)
+    }
+
+    pub fn ceil(&self) -> BigRational {
+        let (quotient, remainder) =
+            BigInt::divide_with_remainder(self.numerator.clone(), self.denominator.clone());
+
+        if remainder > 0 {
+            return BigRational::from(quotient + 1);
+        }
+
+        BigRational::from(quotient)
+    }
+
+    /// Rounds to the nearest integer, halves are rounded away from zero.
+    pub fn round(&self) -> BigRational {
+        let (quotient, remainder) =
+            BigInt::divide_with_remainder(self.numerator.clone(), self.denominator.clone());
+
+        if remainder.abs() * 2 < self.denominator {
+            return BigRational::from(quotient);
+        }
+
+        if self.numerator.positive {
+            BigRational::from(quotient + 1)
+        } else {
+            BigRational::from(quotient - 1)
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::BigInt;
+
+impl BigInt {
+    pub fn fibonacci(n: u64) -> BigInt {
+        BigInt::fibonacci_pair(n).0
+    }
+
+    pub fn lucas(n: u64) -> BigInt {
+        // L(n) = 2 * F(n + 1) - F(n)
+        let (current, next) = BigInt::fibonacci_pair(n);
+        next * 2 - current
+    }
+
+    pub fn bell(n: u64) -> BigInt {
+        Bell::new().nth(n as usize).unwrap()
+    }
+
+    // signed Stirling numbers of the first kind
+    pub fn stirling1(n: u64, k: u64) -> BigInt {
+        BigInt::stirling_row(n, k, |row, _| -BigInt::from(row))
+    }
+
+    // Stirling numbers of the second kind
+    pub fn stirling2(n: u64, k: u64) -> BigInt {
+        BigInt::stirling_row(n, k, |_, column| BigInt::from(column))
+    }
+
+    pub fn partition_count(n: u64) -> BigInt {
+        Partitions::new().nth(n as usize).unwrap()
+    }
+
+    // returns (F(n), F(n + 1)) using fast doubling
+    fn fibonacci_pair(n: u64) -> (BigInt, BigInt) {
+        let mut current = BigInt::default();
+        let mut next: BigInt = 1.into();
+
+        for shift in (0..u64::BITS - n.leading_zeros()).rev() {
+            // F(2k) = F(k) * (2 * F(k + 1) - F(k))
+            // F(2k + 1) = F(k)^2 + F(k + 1)^2
+            let doubled = current.clone() * (next.clone() * 2 - current.clone());
+            let doubled_next = current.clone() * current + next.clone() * next;
+
+            if (n >> shift) & 1 == 0 {
+                (current, next) = (doubled, doubled_next);
+            } else {
+                (current, next) = (doubled_next.clone(), doubled + doubled_next);
+            }
+        }
+
+        (current, next)
+    }
+
+    // s(n + 1, k) = weight(n, k) * s(n, k) + s(n, k - 1)
+    fn stirling_row<F>(n: u64, k: u64, weight: F) -> BigInt
+    where
+        F: Fn(u64, u64) -> BigInt,
+    {
+        if k > n {
+            return BigInt::default();
+        }
+
+        let width = k as usize + 1;
+        let mut row = vec![BigInt::default(); width];
+        row[0] = 1.into();
+
+        for current_row in 0..n {
+            for column in (1..width).rev() {
+                row[column] = weight(current_row, column as u64) * row[column].clone()
+                    + row[column - 1].clone();
+            }
+            row[0] = BigInt::default();
+        }
+
+        row.pop().unwrap()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Fibonacci {
+    current: BigInt,
+    next: BigInt,
+}
+
+impl Fibonacci {
+    pub fn new() -> Fibonacci {
+        Fibonacci {
+            current: BigInt::default(),
+            next: 1.into(),
+        }
+    }
+}
+
+impl Default for Fibonacci {
+    fn default() -> Self {
+        Fibonacci::new()
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let following = self.current.clone() + self.next.clone();
+        let next = std::mem::replace(&mut self.next, following);
+
+        Some(std::mem::replace(&mut self.current, next))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Lucas {
+    current: BigInt,
+    next: BigInt,
+}
+
+impl Lucas {
+    pub fn new() -> Lucas {
+        Lucas {
+            current: 2.into(),
+            next: 1.into(),
+        }
+    }
+}
+
+impl Default for Lucas {
+    fn default() -> Self {
+        Lucas::new()
+    }
+}
+
+impl Iterator for Lucas {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let following = self.current.clone() + self.next.clone();
+        let next = std::mem::replace(&mut self.next, following);
+
+        Some(std::mem::replace(&mut self.current, next))
+    }
+}
+
+// walks the Bell triangle, its rows start with successive Bell numbers
+#[derive(Clone, Debug)]
+pub struct Bell {
+    row: Vec<BigInt>,
+}
+
+impl Bell {
+    pub fn new() -> Bell {
+        Bell { row: vec![] }
+    }
+}
+
+impl Default for Bell {
+    fn default() -> Self {
+        Bell::new()
+    }
+}
+
+impl Iterator for Bell {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut row: Vec<BigInt> = match self.row.last() {
+            Some(last) => vec![last.clone()],
+            None => vec![1.into()],
+        };
+
+        for previous in &self.row {
+            row.push(row.last().unwrap().clone() + previous.clone());
+        }
+
+        self.row = row;
+
+        Some(self.row[0].clone())
+    }
+}
+
+// partition numbers from Euler's pentagonal number theorem
+#[derive(Clone, Debug)]
+pub struct Partitions {
+    computed: Vec<BigInt>,
+}
+
+impl Partitions {
+    pub fn new() -> Partitions {
+        Partitions { computed: vec![] }
+    }
+}
+
+impl Default for Partitions {
+    fn default() -> Self {
+        Partitions::new()
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.computed.len();
+
+        //p(n) = sum of (-1)^(k + 1) * (p(n - k(3k - 1) / 2) + p(n - k(3k + 1) / 2))
+        let mut count = if n == 0 { 1.into() } else { BigInt::default() };
+
+        for k in 1.. {
+            let pentagonal = k * (3 * k - 1) / 2;
+            if pentagonal > n {
+                break;
+            }
+
+            let mut term = self.computed[n - pentagonal].clone();
+            if pentagonal + k <= n {
+                term += self.computed[n - pentagonal - k].clone();
+            }
+
+            if k % 2 == 1 {
+                count += term;
+            } else {
+                count -= term;
+            }
+        }
+
+        self.computed.push(count.clone());
+
+        Some(count)
+    }
+}
@@ -2,65 +2,88 @@ use std::str::FromStr;
 
 use num_traits::Pow;
 
+use crate::BigDecimal;
+use crate::BigFloat;
 use crate::BigInt;
 use crate::BigIntError;
+use crate::BigRational;
+use crate::BigUint;
+use crate::GaussianInt;
+use crate::LinearDiophantine;
+use crate::Matrix;
+use crate::Polynomial;
+use crate::RoundingMode;
+use crate::number_theory;
+use crate::{
+    Bell, Convergents, Fibonacci, Lucas, Partitions, Pell, continued_fraction, crt, discrete_log,
+    e_digits, from_continued_fraction, integer_relation, linear_diophantine,
+    linear_diophantine_system, lucas_lehmer, lucas_sequence, pi_digits, sqrt_continued_fraction,
+};
+use crate::{I256, I1024, Int, U256, U512, U1024, Uint};
+
+#[cfg(feature = "rand")]
+use crate::RandBigInt;
+#[cfg(feature = "rand")]
+use rand::SeedableRng;
+#[cfg(feature = "rand")]
+use rand::rngs::StdRng;
 
 #[test]
 fn default() {
     let def = BigInt::default();
     assert!(def.positive);
-    assert_eq!(def.numbers, [0].to_vec());
+    assert_eq!(def.magnitude.numbers, [0].to_vec());
 }
 
 #[test]
 fn new() {
     let new = BigInt::new();
     assert!(new.positive);
-    assert_eq!(new.numbers, [0].to_vec());
+    assert_eq!(new.magnitude.numbers, [0].to_vec());
 }
 
 #[test]
 fn from() {
     let x = BigInt::from(20);
     assert!(x.positive);
-    assert_eq!(x.numbers, [2, 0].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0].to_vec());
     let x = BigInt::from(-20);
     assert!(!x.positive);
-    assert_eq!(x.numbers, [2, 0].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0].to_vec());
     let x = BigInt::from(-320020000981234567890_i128);
     assert!(!x.positive);
     assert_eq!(
-        x.numbers,
+        x.magnitude.numbers,
         [3, 2, 0, 0, 2, 0, 0, 0, 0, 9, 8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0].to_vec()
     );
     let x = BigInt::from(0);
     assert!(x.positive);
-    assert_eq!(x.numbers, [0].to_vec());
+    assert_eq!(x.magnitude.numbers, [0].to_vec());
     let x = BigInt::from(-0);
     assert!(x.positive);
-    assert_eq!(x.numbers, [0].to_vec());
+    assert_eq!(x.magnitude.numbers, [0].to_vec());
 }
 
 #[test]
 fn from_string_numbers() {
     let x = BigInt::from_str("20").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [2, 0].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0].to_vec());
     let x = BigInt::from_str("666").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [6, 6, 6].to_vec());
+    assert_eq!(x.magnitude.numbers, [6, 6, 6].to_vec());
     let x = BigInt::from_str("-20").unwrap();
     assert!(!x.positive);
-    assert_eq!(x.numbers, [2, 0].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0].to_vec());
     let x = BigInt::from_str("-320020000981234567890").unwrap();
     assert!(!x.positive);
     assert_eq!(
-        x.numbers,
+        x.magnitude.numbers,
         [3, 2, 0, 0, 2, 0, 0, 0, 0, 9, 8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0].to_vec()
     );
     let x = BigInt::from_str("-0").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [0].to_vec());
+    assert_eq!(x.magnitude.numbers, [0].to_vec());
     let x = BigInt::from_str("0sw");
     assert!(x.is_err());
     let x = BigInt::from_str("0 2020000");
@@ -77,19 +100,19 @@ fn from_string_numbers() {
 fn from_string_words_from_str_digits() {
     let x = BigInt::from_str("two zero     ").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [2, 0].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0].to_vec());
     let x = BigInt::from_str("minus two four").unwrap();
     assert!(!x.positive);
-    assert_eq!(x.numbers, [2, 4].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 4].to_vec());
     let x = BigInt::from_str("two five five zero zero two one").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [2, 5, 5, 0, 0, 2, 1].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 5, 5, 0, 0, 2, 1].to_vec());
     let x = BigInt::from_str("minus two     zero zero zero zero zero one").unwrap();
     assert!(!x.positive);
-    assert_eq!(x.numbers, [2, 0, 0, 0, 0, 0, 1].to_vec());
+    assert_eq!(x.magnitude.numbers, [2, 0, 0, 0, 0, 0, 1].to_vec());
     let x = BigInt::from_str("zero").unwrap();
     assert!(x.positive);
-    assert_eq!(x.numbers, [0].to_vec());
+    assert_eq!(x.magnitude.numbers, [0].to_vec());
     let x = BigInt::from_str("onse");
     assert!(x.is_err());
     let x = BigInt::from_str("        ");
@@ -100,7 +123,7 @@ fn from_string_words_from_str_digits() {
     assert!(x.is_err());
     let x = BigInt::from_str("- five four").unwrap();
     assert!(!x.positive);
-    assert_eq!(x.numbers, [5, 4].to_vec());
+    assert_eq!(x.magnitude.numbers, [5, 4].to_vec());
 }
 
 #[test]
@@ -112,7 +135,10 @@ fn try_into() {
     let x = BigInt::from(-123);
     assert_eq!(-123_i128, x.try_into().unwrap());
     let x = BigInt::from(-123);
-    assert_eq!(123_u128, x.try_into().unwrap());
+    assert!(matches!(
+        u128::try_from(x),
+        Err(BigIntError::NegativeNumber)
+    ));
     let x = BigInt::from(0);
     assert_eq!(0_i128, x.try_into().unwrap());
     let x = BigInt::from(0);
@@ -124,15 +150,15 @@ fn try_into() {
     let x = BigInt::from(-123);
     assert_eq!(-123_i8, x.try_into().unwrap());
     let x = BigInt::from(-123);
-    assert_eq!(123_u8, x.try_into().unwrap());
+    assert!(matches!(u8::try_from(x), Err(BigIntError::NegativeNumber)));
     let x = BigInt::from(-123);
     assert_eq!(-123_i16, x.try_into().unwrap());
     let x = BigInt::from(-123);
-    assert_eq!(123_u32, x.try_into().unwrap());
+    assert!(matches!(u32::try_from(x), Err(BigIntError::NegativeNumber)));
     let x = BigInt::from(-123);
     assert_eq!(-123_i64, x.try_into().unwrap());
     let x = BigInt::from(-123);
-    assert_eq!(123_u64, x.try_into().unwrap());
+    assert!(matches!(u64::try_from(x), Err(BigIntError::NegativeNumber)));
 }
 
 #[test]
@@ -149,6 +175,74 @@ fn try_into_panic_u128() {
     assert_eq!(666_u128, x.try_into().unwrap());
 }
 
+#[test]
+fn try_from_primitives() {
+    macro_rules! check_bounds {
+        ($($t:ty),*) => {
+            $(
+                let (min, max) = (BigInt::from(<$t>::MIN), BigInt::from(<$t>::MAX));
+                assert_eq!(<$t>::try_from(&min).unwrap(), <$t>::MIN);
+                assert_eq!(<$t>::try_from(&max).unwrap(), <$t>::MAX);
+                assert_eq!(<$t>::try_from(BigInt::from(0)).unwrap(), 0);
+                assert_eq!(<$t>::try_from(BigInt::from(100)).unwrap(), 100);
+                assert!(matches!(
+                    <$t>::try_from(max + 1),
+                    Err(BigIntError::LargeNumber)
+                ));
+                if <$t>::MIN == 0 {
+                    assert!(matches!(
+                        <$t>::try_from(min - 1),
+                        Err(BigIntError::NegativeNumber)
+                    ));
+                } else {
+                    assert!(matches!(
+                        <$t>::try_from(min - 1),
+                        Err(BigIntError::SmallNumber)
+                    ));
+                }
+            )*
+        };
+    }
+
+    check_bounds!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    );
+
+    let huge = BigInt::from(10).pow(50);
+    assert!(matches!(i8::try_from(&huge), Err(BigIntError::LargeNumber)));
+    assert!(matches!(
+        u128::try_from(&huge),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        i128::try_from(-huge.clone()),
+        Err(BigIntError::SmallNumber)
+    ));
+    assert!(matches!(
+        u8::try_from(-huge),
+        Err(BigIntError::NegativeNumber)
+    ));
+    assert_eq!(i64::try_from(BigInt::from(-123)).unwrap(), -123);
+    assert_eq!(
+        usize::try_from(BigInt::from(usize::MAX)).unwrap(),
+        usize::MAX
+    );
+    assert_eq!(
+        u64::try_from(BigInt::from_str("18446744073709551615").unwrap()).unwrap(),
+        u64::MAX
+    );
+    assert_eq!(format!("{}", BigIntError::SmallNumber), "Too small");
+
+    //pointer-sized comparisons
+    let x = BigUint::from(usize::MAX);
+    assert_eq!(x, usize::MAX);
+    assert!(x > usize::MAX - 1);
+    let x = BigInt::from(isize::MIN);
+    assert_eq!(x, isize::MIN);
+    assert!(x < isize::MIN + 1);
+    assert_eq!(BigInt::from(usize::MAX), usize::MAX);
+}
+
 #[test]
 fn display() {
     let x = BigInt::from(1003);
@@ -225,6 +319,7 @@ fn not() {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn equal() {
     let x = BigInt::from(10);
     let y = BigInt::from(10);
@@ -253,6 +348,7 @@ fn equal() {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn equal_int() {
     let x = BigInt::from(10);
     assert!(x == 10);
@@ -273,6 +369,7 @@ fn equal_int() {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn equal_str() {
     let x = BigInt::from(10);
     assert!(x == "10");
@@ -1071,6 +1168,7 @@ fn hexadecimal() {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn progtest_tests() {
     let mut a = BigInt::from(10);
     a += BigInt::from(20);
@@ -1146,3 +1244,3041 @@ fn progtest_tests() {
     assert!(!(a == -87654321));
     assert!(a != -87654321);
 }
+
+#[test]
+fn chinese_remainder() {
+    let x = crt(&[
+        (2.into(), 3.into()),
+        (3.into(), 5.into()),
+        (2.into(), 7.into()),
+    ]);
+    assert_eq!(x, Some((23.into(), 105.into())));
+
+    let x = crt(&[(2.into(), 4.into()), (4.into(), 6.into())]);
+    assert_eq!(x, Some((10.into(), 12.into())));
+
+    let x = crt(&[(1.into(), 4.into()), (2.into(), 6.into())]);
+    assert_eq!(x, None);
+
+    let x = crt(&[(BigInt::from(-1), 4.into()), (BigInt::from(-1), 9.into())]);
+    assert_eq!(x, Some((35.into(), 36.into())));
+
+    let x = crt(&[(5.into(), 0.into())]);
+    assert_eq!(x, None);
+
+    let x = crt(&[(5.into(), BigInt::from(-3))]);
+    assert_eq!(x, None);
+
+    let x = crt(&[(17.into(), 5.into())]);
+    assert_eq!(x, Some((2.into(), 5.into())));
+
+    let x = crt(&[]);
+    assert_eq!(x, Some((0.into(), 1.into())));
+
+    let x = crt(&[
+        (3.into(), 10.into()),
+        (3.into(), 10.into()),
+        (13.into(), 20.into()),
+    ]);
+    assert_eq!(x, Some((13.into(), 20.into())));
+}
+
+#[test]
+fn chinese_remainder_many_congruences() {
+    let primes: Vec<u32> = (2..400_u32)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+
+    let congruences: Vec<(BigInt, BigInt)> = primes
+        .iter()
+        .enumerate()
+        .map(|(index, &prime)| (BigInt::from((index * index) as u64), BigInt::from(prime)))
+        .collect();
+
+    let (residue, modulus) = crt(&congruences).unwrap();
+
+    let product = primes
+        .iter()
+        .fold(BigInt::from(1), |product, &prime| product * prime);
+    assert_eq!(modulus, product);
+    assert!(residue < modulus);
+
+    for (expected, prime) in congruences {
+        assert_eq!(residue.clone() % prime.clone(), expected % prime);
+    }
+}
+
+#[test]
+fn discrete_logarithm() {
+    let x = discrete_log(&5.into(), &8.into(), &23.into());
+    assert_eq!(x, Some(6.into()));
+
+    let x = discrete_log(&5.into(), &1.into(), &23.into());
+    assert_eq!(x, Some(0.into()));
+
+    let x = discrete_log(&5.into(), &5.into(), &23.into());
+    assert_eq!(x, Some(1.into()));
+
+    // 4 generates the subgroup of order 11
+    let x = discrete_log(&4.into(), &8.into(), &23.into());
+    assert_eq!(x, Some(7.into()));
+
+    let x = discrete_log(&27.into(), &8.into(), &23.into());
+    assert_eq!(x, Some(7.into()));
+
+    // 5 is not a quadratic residue modulo 23
+    let x = discrete_log(&4.into(), &5.into(), &23.into());
+    assert_eq!(x, None);
+
+    let x = discrete_log(&0.into(), &5.into(), &23.into());
+    assert_eq!(x, None);
+
+    let x = discrete_log(&1.into(), &5.into(), &23.into());
+    assert_eq!(x, None);
+}
+
+#[test]
+#[should_panic(expected = "modulus has to be prime!")]
+fn discrete_logarithm_composite_modulus() {
+    let _ = discrete_log(&2.into(), &4.into(), &21.into());
+}
+
+#[test]
+fn discrete_logarithm_smooth_order() {
+    // p - 1 = 2 * 11 * 13 * 23 * 29^2 * 31 * 47^2 * 101^2 * 103 * 113
+    let p = BigInt::from_str("44978574823529313539").unwrap();
+    let g = BigInt::from(2);
+    let h = BigInt::from_str("17187838901439407626").unwrap();
+
+    let x = discrete_log(&g, &h, &p);
+    assert_eq!(x, Some(BigInt::from_str("20523116056193102073").unwrap()));
+}
+
+#[test]
+fn discrete_logarithm_prime_order() {
+    // 9173812 generates the subgroup of order 1000003 modulo 36000109
+    let p = BigInt::from(36000109);
+    let order = BigInt::from(1000003);
+    let gamma = BigInt::from(9173812);
+    let target = BigInt::from(33047306);
+
+    let x = number_theory::baby_step_giant_step(&gamma, &target, &order, &p);
+    assert_eq!(x, Some(777777.into()));
+
+    let x = number_theory::pollard_rho_log(&gamma, &target, &order, &p);
+    assert_eq!(x, Some(777777.into()));
+
+    let x = discrete_log(&gamma, &target, &p);
+    assert_eq!(x, Some(777777.into()));
+}
+
+#[test]
+fn prime_factorization() {
+    let x = number_theory::factorize(&360.into());
+    assert_eq!(x, vec![(2.into(), 3), (3.into(), 2), (5.into(), 1)]);
+
+    let x = number_theory::factorize(&BigInt::from(1000036000099_u64));
+    assert_eq!(x, vec![(1000003.into(), 1), (1000033.into(), 1)]);
+
+    let x = number_theory::factorize(&1.into());
+    assert_eq!(x, vec![]);
+
+    assert!(number_theory::is_probable_prime(&2.into()));
+    assert!(number_theory::is_probable_prime(&1000000007.into()));
+    assert!(number_theory::is_probable_prime(
+        &BigInt::from_str("2305843009213693951").unwrap()
+    ));
+    assert!(!number_theory::is_probable_prime(&1.into()));
+    assert!(!number_theory::is_probable_prime(&561.into()));
+    assert!(!number_theory::is_probable_prime(&BigInt::from(
+        1000036000099_u64
+    )));
+}
+
+#[test]
+fn factorial() {
+    assert_eq!(BigInt::factorial(0), 1);
+    assert_eq!(BigInt::factorial(1), 1);
+    assert_eq!(BigInt::factorial(5), 120);
+    assert_eq!(BigInt::factorial(20), 2432902008176640000_u64);
+    assert_eq!(BigInt::factorial(30), "265252859812191058636308480000000");
+    assert_eq!(
+        BigInt::factorial(52),
+        "80658175170943878571660636856403766975289505440883277824000000000000"
+    );
+
+    let product = (1..=150).fold(BigInt::from(1), |product, number| product * number);
+    assert_eq!(BigInt::factorial(150), product);
+}
+
+#[test]
+fn double_factorial() {
+    assert_eq!(BigInt::double_factorial(0), 1);
+    assert_eq!(BigInt::double_factorial(1), 1);
+    assert_eq!(BigInt::double_factorial(2), 2);
+    assert_eq!(BigInt::double_factorial(7), 105);
+    assert_eq!(BigInt::double_factorial(8), 384);
+    assert_eq!(BigInt::double_factorial(25), 7905853580625_u64);
+}
+
+#[test]
+fn binomial() {
+    assert_eq!(BigInt::binomial(5, 2), 10);
+    assert_eq!(BigInt::binomial(10, 0), 1);
+    assert_eq!(BigInt::binomial(10, 10), 1);
+    assert_eq!(BigInt::binomial(0, 0), 1);
+    assert_eq!(BigInt::binomial(3, 5), 0);
+    assert_eq!(BigInt::binomial(52, 5), 2598960);
+    assert_eq!(BigInt::binomial(100, 50), "100891344545564193334812497256");
+
+    //few factors of a huge n
+    let n = 1_000_000_000_000_u64;
+    assert_eq!(BigInt::binomial(n, 2), BigInt::from(n) * (n - 1) / 2);
+    assert_eq!(BigInt::binomial(n, n - 2), BigInt::binomial(n, 2));
+    assert_eq!(BigInt::binomial(u64::MAX, 1), u64::MAX);
+
+    //both paths agree around the switch
+    for k in 0..=100 {
+        let expected = BigInt::factorial(100) / (BigInt::factorial(k) * BigInt::factorial(100 - k));
+        assert_eq!(BigInt::binomial(100, k), expected);
+    }
+}
+
+#[test]
+fn multinomial() {
+    assert_eq!(BigInt::multinomial(&[2, 3, 4]), 1260);
+    assert_eq!(BigInt::multinomial(&[5]), 1);
+    assert_eq!(BigInt::multinomial(&[]), 1);
+    assert_eq!(BigInt::multinomial(&[0, 0, 3]), 1);
+    assert_eq!(BigInt::multinomial(&[1, 1, 1, 1]), 24);
+    assert_eq!(BigInt::multinomial(&[40, 60]), BigInt::binomial(100, 40));
+    assert_eq!(
+        BigInt::multinomial(&[1, 2, 97]),
+        BigInt::binomial(100, 3) * 3
+    );
+
+    let n = 1_000_000_000_000_u64;
+    assert_eq!(
+        BigInt::multinomial(&[2, n, 1]),
+        BigInt::binomial(n + 3, 3) * 3
+    );
+}
+
+#[test]
+#[should_panic(expected = "multinomial total overflows u64!")]
+fn multinomial_overflow() {
+    let _ = BigInt::multinomial(&[u64::MAX, 1]);
+}
+
+#[test]
+fn falling_and_rising_factorial() {
+    assert_eq!(BigInt::falling_factorial(10, 3), 720);
+    assert_eq!(BigInt::falling_factorial(10, 0), 1);
+    assert_eq!(BigInt::falling_factorial(-3, 2), 12);
+    assert_eq!(BigInt::falling_factorial(3, 5), 0);
+    assert_eq!(BigInt::falling_factorial(20, 20), BigInt::factorial(20));
+    assert_eq!(BigInt::rising_factorial(3, 4), 360);
+    assert_eq!(BigInt::rising_factorial(3, 0), 1);
+    assert_eq!(BigInt::rising_factorial(-2, 3), 0);
+    assert_eq!(BigInt::rising_factorial(-5, 2), 20);
+    assert_eq!(BigInt::rising_factorial(1, 20), BigInt::factorial(20));
+}
+
+#[test]
+fn catalan() {
+    assert_eq!(BigInt::catalan(0), 1);
+    assert_eq!(BigInt::catalan(1), 1);
+    assert_eq!(BigInt::catalan(2), 2);
+    assert_eq!(BigInt::catalan(3), 5);
+    assert_eq!(BigInt::catalan(10), 16796);
+}
+
+#[test]
+#[should_panic(expected = "catalan index overflows u64!")]
+fn catalan_overflow() {
+    let _ = BigInt::catalan(u64::MAX);
+}
+
+#[test]
+fn power_of_large_exponent() {
+    let x = BigInt::from(2).pow(200);
+    assert_eq!(
+        x,
+        "1606938044258990275541962092341162602522202993782792835301376"
+    );
+    let x = BigInt::from(-3).pow(41);
+    assert_eq!(x, "-36472996377170786403");
+    let x = BigInt::from(-3).pow(40);
+    assert_eq!(x, "12157665459056928801");
+}
+
+#[test]
+fn power_matches_repeated_multiplication() {
+    for base in [-7, -2, 2, 3, 10, 123456789] {
+        let base = BigInt::from(base);
+        let mut expected = BigInt::from(1);
+
+        for exponent in 0..=70 {
+            assert_eq!(base.clone().pow(exponent), expected);
+
+            expected *= base.clone();
+        }
+    }
+}
+
+#[test]
+fn fibonacci() {
+    assert_eq!(BigInt::fibonacci(0), 0);
+    assert_eq!(BigInt::fibonacci(1), 1);
+    assert_eq!(BigInt::fibonacci(2), 1);
+    assert_eq!(BigInt::fibonacci(11), 89);
+    assert_eq!(BigInt::fibonacci(100), 354224848179261915075_u128);
+    assert_eq!(
+        BigInt::fibonacci(1000),
+        "43466557686937456435688527675040625802564660517371780402481729089536555417949051890403879840079255169295922593080322634775209689623239873322471161642996440906533187938298969649928516003704476137795166849228875"
+    );
+
+    let x: Vec<BigInt> = Fibonacci::new().take(12).collect();
+    assert_eq!(
+        x,
+        [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89].map(BigInt::from)
+    );
+
+    let x = Fibonacci::new().nth(300).unwrap();
+    assert_eq!(x, BigInt::fibonacci(300));
+}
+
+#[test]
+fn lucas() {
+    assert_eq!(BigInt::lucas(0), 2);
+    assert_eq!(BigInt::lucas(1), 1);
+    assert_eq!(BigInt::lucas(9), 76);
+    assert_eq!(BigInt::lucas(100), 792070839848372253127_u128);
+
+    let x: Vec<BigInt> = Lucas::new().take(10).collect();
+    assert_eq!(x, [2, 1, 3, 4, 7, 11, 18, 29, 47, 76].map(BigInt::from));
+
+    let x = Lucas::new().nth(250).unwrap();
+    assert_eq!(x, BigInt::lucas(250));
+}
+
+#[test]
+fn bell() {
+    assert_eq!(BigInt::bell(0), 1);
+    assert_eq!(BigInt::bell(1), 1);
+    assert_eq!(BigInt::bell(10), 115975);
+    assert_eq!(BigInt::bell(20), 51724158235372_u64);
+
+    let x: Vec<BigInt> = Bell::new().take(10).collect();
+    assert_eq!(
+        x,
+        [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147].map(BigInt::from)
+    );
+}
+
+#[test]
+fn stirling() {
+    assert_eq!(BigInt::stirling2(10, 3), 9330);
+    assert_eq!(BigInt::stirling2(0, 0), 1);
+    assert_eq!(BigInt::stirling2(5, 0), 0);
+    assert_eq!(BigInt::stirling2(5, 5), 1);
+    assert_eq!(BigInt::stirling2(3, 5), 0);
+    assert_eq!(BigInt::stirling1(10, 3), -1172700);
+    assert_eq!(BigInt::stirling1(0, 0), 1);
+    assert_eq!(BigInt::stirling1(5, 0), 0);
+    assert_eq!(BigInt::stirling1(4, 1), -6);
+    assert_eq!(BigInt::stirling1(5, 1), 24);
+    assert_eq!(BigInt::stirling1(2, 7), 0);
+
+    let x = (0..=15).fold(BigInt::default(), |sum, k| sum + BigInt::stirling2(15, k));
+    assert_eq!(x, BigInt::bell(15));
+}
+
+#[test]
+fn partition_count() {
+    assert_eq!(BigInt::partition_count(0), 1);
+    assert_eq!(BigInt::partition_count(5), 7);
+    assert_eq!(BigInt::partition_count(100), 190569292);
+    assert_eq!(
+        BigInt::partition_count(1000),
+        "24061467864032622473692149727991"
+    );
+
+    let x: Vec<BigInt> = Partitions::new().take(12).collect();
+    assert_eq!(
+        x,
+        [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56].map(BigInt::from)
+    );
+}
+
+#[test]
+fn lucas_sequences() {
+    let modulus = BigInt::from(1000000007);
+
+    // P = 1, Q = -1 gives Fibonacci and Lucas numbers
+    let (u, v) = lucas_sequence(&1.into(), &BigInt::from(-1), &100.into(), &modulus);
+    assert_eq!(u, BigInt::fibonacci(100) % modulus.clone());
+    assert_eq!(v, BigInt::lucas(100) % modulus.clone());
+
+    let (u, v) = lucas_sequence(&1.into(), &BigInt::from(-1), &0.into(), &modulus);
+    assert_eq!(u, 0);
+    assert_eq!(v, 2);
+
+    let (u, v) = lucas_sequence(&1.into(), &BigInt::from(-1), &1.into(), &modulus);
+    assert_eq!(u, 1);
+    assert_eq!(v, 1);
+
+    // P = 3, Q = 2 gives U_k = 2^k - 1 and V_k = 2^k + 1
+    let modulus = BigInt::from(10).pow(40);
+    let (u, v) = lucas_sequence(&3.into(), &2.into(), &100.into(), &modulus);
+    assert_eq!(u, BigInt::from(2).pow(100) - 1);
+    assert_eq!(v, BigInt::from(2).pow(100) + 1);
+
+    // even modulus
+    let (u, v) = lucas_sequence(&1.into(), &BigInt::from(-1), &30.into(), &16.into());
+    assert_eq!(u, 832040 % 16);
+    assert_eq!(v, 1860498 % 16);
+}
+
+#[test]
+#[should_panic(expected = "modulus has to be positive!")]
+fn lucas_sequence_zero_modulus() {
+    lucas_sequence(&1.into(), &BigInt::from(-1), &10.into(), &0.into());
+}
+
+#[test]
+#[should_panic(expected = "index has to be non-negative!")]
+fn lucas_sequence_negative_index() {
+    lucas_sequence(&1.into(), &BigInt::from(-1), &BigInt::from(-3), &7.into());
+}
+
+#[test]
+fn mersenne_primes() {
+    let mersenne_exponents: Vec<u64> = (0..130).filter(|&p| lucas_lehmer(p)).collect();
+    assert_eq!(
+        mersenne_exponents,
+        [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127]
+    );
+
+    assert!(lucas_lehmer(521));
+    assert!(lucas_lehmer(607));
+    assert!(lucas_lehmer(1279));
+    assert!(!lucas_lehmer(523));
+    assert!(!lucas_lehmer(1277));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_bigint() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let bound = BigInt::from(2).pow(100);
+
+    for _ in 0..100 {
+        let x = rng.gen_bigint(100);
+        assert!(x < bound.clone());
+        assert!(x > -bound.clone());
+    }
+
+    let x = rng.gen_bigint(0);
+    assert_eq!(x, 0);
+
+    let x: Vec<BigInt> = (0..5).map(|_| rng.gen_bigint(200)).collect();
+    assert!(x.iter().any(|number| *number < 0));
+    assert!(x.iter().any(|number| *number > 0));
+
+    let mut first = StdRng::seed_from_u64(7);
+    let mut second = StdRng::seed_from_u64(7);
+    assert_eq!(first.gen_bigint(300), second.gen_bigint(300));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_bigint_below() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut counts = [0; 6];
+
+    for _ in 0..6000 {
+        let x = rng.gen_biguint_below(&6_u8.into());
+        let index = (0..6_u8).position(|value| x == value).unwrap();
+        counts[index] += 1;
+    }
+
+    for count in counts {
+        assert!((850..1150).contains(&count));
+    }
+
+    let bound = BigUint::from_str("1000000000000000000000000000000").unwrap();
+    for _ in 0..50 {
+        let x = rng.gen_biguint_below(&bound);
+        assert!(x < bound);
+    }
+
+    let x = rng.gen_biguint_below(&1_u8.into());
+    assert_eq!(x, 0_u8);
+
+    let x = rng.gen_biguint(70);
+    assert!(x < 1_u128 << 70);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn random_bigint_below_zero() {
+    let mut rng = StdRng::seed_from_u64(1);
+    rng.gen_biguint_below(&0_u8.into());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_bigint_range() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let low = BigInt::from(-3);
+    let high = BigInt::from(3);
+
+    let x: Vec<BigInt> = (0..200)
+        .map(|_| rng.gen_bigint_range(&low, &high))
+        .collect();
+    assert!(x.iter().all(|number| *number >= low && *number < high));
+    assert!(x.contains(&low));
+    assert!(x.contains(&(high - 1)));
+
+    let low = BigInt::from_str("-99999999999999999999999").unwrap();
+    let high = BigInt::from_str("-99999999999999999999000").unwrap();
+    let x = rng.gen_bigint_range(&low, &high);
+    assert!(x >= low && x < high);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_prime() {
+    let mut rng = StdRng::seed_from_u64(5);
+
+    let x = rng.gen_prime(2);
+    assert!(x == 2 || x == 3);
+
+    let x = rng.gen_prime(16);
+    assert!(number_theory::is_probable_prime(&x));
+    assert!(x >= 1 << 15);
+    assert!(x < 1 << 16);
+
+    let x = rng.gen_prime(64);
+    assert!(number_theory::is_probable_prime(&x));
+    assert!(x >= 1_u64 << 63);
+}
+
+#[test]
+fn biguint() {
+    let x = BigUint::from(20_u8);
+    assert_eq!(x.numbers, [2, 0].to_vec());
+    let x = BigUint::from(0_u64);
+    assert_eq!(x.numbers, [0].to_vec());
+    let x = BigUint::from_str("00320020000981234567890").unwrap();
+    assert_eq!(x, 320020000981234567890_u128);
+    let x = BigUint::from_str("two four").unwrap();
+    assert_eq!(x, 24_u8);
+    let x = BigUint::from_str("-0").unwrap();
+    assert_eq!(x, 0_u8);
+    let x = BigUint::from_str("-20");
+    assert!(matches!(x, Err(BigIntError::NegativeNumber)));
+    let x = BigUint::from_str("2a");
+    assert!(matches!(x, Err(BigIntError::NaN)));
+
+    let x = BigUint::from(1003_u16);
+    assert_eq!(format!("{x}"), "1003");
+    assert_eq!(format!("{x:0>10}"), "0000001003");
+    assert_eq!(format!("{x:+}"), "+1003");
+    assert_eq!(format!("{x:#b}"), "0b1111101011");
+    assert_eq!(format!("{x:#X}"), "0x3EB");
+    assert_eq!(format!("{x:x}"), "3eb");
+    let x = BigUint::new();
+    assert_eq!(format!("{x:x}"), "0");
+}
+
+#[test]
+fn biguint_math() {
+    let mut x = BigUint::from(99999_u32);
+    x += 1_u8;
+    assert_eq!(x, 100000_u32);
+    x -= 99999_u32;
+    assert_eq!(x, 1_u8);
+    x *= 123456789_u32;
+    assert_eq!(x, 123456789_u32);
+    x /= 1000_u16;
+    assert_eq!(x, 123456_u32);
+    x %= 1000_u16;
+    assert_eq!(x, 456_u16);
+
+    let x = BigUint::from(2_u8).pow(100_u8);
+    assert_eq!(x, 1267650600228229401496703205376_u128);
+    let x = BigUint::from(12_u8) & 10_u8;
+    assert_eq!(x, 8_u8);
+    let x = BigUint::from(12_u8) | 3_u8;
+    assert_eq!(x, 15_u8);
+    let x = BigUint::from(12_u8) ^ 10_u8;
+    assert_eq!(x, 6_u8);
+    let x = BigUint::from(11_u8) << 2_u8;
+    assert_eq!(x, 44_u8);
+    let x = BigUint::from(11_u8) >> 2_u8;
+    assert_eq!(x, 2_u8);
+
+    let x = BigUint::from(10_u8);
+    assert_eq!(x.checked_sub(&3_u8.into()), Some(7_u8.into()));
+    assert_eq!(x.checked_sub(&10_u8.into()), Some(0_u8.into()));
+    assert_eq!(x.checked_sub(&11_u8.into()), None);
+
+    let x = BigUint::from(5_u8);
+    let y = BigUint::from(10_u8);
+    assert!(x < y);
+    assert!(y > 9_u8);
+    assert_eq!(x.max(y), 10_u8);
+}
+
+#[test]
+#[should_panic]
+fn biguint_sub_underflow() {
+    let x = BigUint::from(3_u8);
+    let _ = x - 4_u8;
+}
+
+#[test]
+#[should_panic]
+fn biguint_div_by_zero() {
+    let x = BigUint::from(3_u8);
+    let _ = x / 0_u8;
+}
+
+#[test]
+fn biguint_conversions() {
+    let x = BigUint::try_from(BigInt::from(42));
+    assert_eq!(x.unwrap(), 42_u8);
+    let x = BigUint::try_from(&BigInt::from(0));
+    assert_eq!(x.unwrap(), 0_u8);
+    let x = BigUint::try_from(BigInt::from(-42));
+    assert!(matches!(x, Err(BigIntError::NegativeNumber)));
+
+    let x = BigInt::from(BigUint::from(42_u8));
+    assert_eq!(x, 42);
+    assert!(x.positive);
+    let x = -BigInt::from(&BigUint::from(42_u8));
+    assert_eq!(x, -42);
+
+    let x = BigInt::from(i128::MIN);
+    assert_eq!(x, "-170141183460469231731687303715884105728");
+
+    assert_eq!(
+        format!("{}", BigIntError::NegativeNumber),
+        "Negative number"
+    );
+}
+
+#[test]
+fn signed_remainder() {
+    assert_eq!(BigInt::from(7) % 2, 1);
+    assert_eq!(BigInt::from(-7) % 2, -1);
+    assert_eq!(BigInt::from(7) % -2, 1);
+    assert_eq!(BigInt::from(-7) % -2, -1);
+    assert_eq!(BigInt::from(-3) % 5, -3);
+    assert_eq!(BigInt::from(-3) / 5, 0);
+    assert_eq!(BigInt::from(-7) / 2, -3);
+    assert!(BigInt::from_str("-").is_err());
+    assert_eq!(BigInt::from_str("-007").unwrap(), -7);
+
+    //a == (a / b) * b + a % b with |a % b| < |b| and the sign of a, like i64
+    for a in -12_i64..=12 {
+        for b in [-5_i64, -3, -1, 1, 2, 7] {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(x.clone() / y.clone(), a / b);
+            assert_eq!(x.clone() % y.clone(), a % b);
+            assert_eq!((x.clone() / y.clone()) * y.clone() + x.clone() % y, x);
+        }
+    }
+
+    assert_eq!(format!("{:x}", BigInt::from(0)), "0");
+    assert_eq!(format!("{:#X}", BigInt::from(0)), "0x0");
+}
+
+#[test]
+fn signed_remainder_dependents() {
+    //a zero quotient with a negative remainder, which used to come out positive
+    let x = BigRational::new(-1, 3);
+    assert_eq!(x.floor(), -1);
+    assert_eq!(x.ceil(), 0);
+    assert_eq!(x.round(), 0);
+    assert_eq!(BigRational::new(-2, 3).round(), -1);
+    assert_eq!(BigRational::new(-1, 2).round(), -1);
+    assert_eq!(BigRational::new(1, -3).floor(), -1);
+
+    let x = BigDecimal::new(-1, 1);
+    assert_eq!(x.with_scale(0, RoundingMode::Floor), BigDecimal::from(-1));
+    assert_eq!(x.with_scale(0, RoundingMode::Ceiling), BigDecimal::from(0));
+    assert_eq!(x.with_scale(0, RoundingMode::Up), BigDecimal::from(-1));
+    assert_eq!(x.with_scale(0, RoundingMode::Down), BigDecimal::from(0));
+    let x = BigDecimal::new(-5, 1);
+    assert_eq!(x.with_scale(0, RoundingMode::HalfUp), BigDecimal::from(-1));
+    assert_eq!(x.with_scale(0, RoundingMode::HalfDown), BigDecimal::from(0));
+    assert_eq!(x.with_scale(0, RoundingMode::HalfEven), BigDecimal::from(0));
+    assert_eq!(
+        BigDecimal::from(1).divide(&BigDecimal::from(-3), 2, RoundingMode::Floor),
+        BigDecimal::new(-34, 2)
+    );
+}
+
+#[test]
+fn rational() {
+    let x = BigRational::new(6, -8);
+    assert_eq!(x.numerator(), &BigInt::from(-3));
+    assert_eq!(x.denominator(), &BigInt::from(4));
+    assert_eq!(format!("{x}"), "-3/4");
+    let x = BigRational::new(0, -5);
+    assert_eq!(x, BigRational::default());
+    assert_eq!(format!("{x}"), "0");
+    let x = BigRational::new(10, 5);
+    assert!(x.is_integer());
+    assert_eq!(x, 2);
+    assert_eq!(format!("{x:+}"), "+2");
+    let x = BigRational::new(1, 3);
+    assert_eq!(format!("{x:->6}"), "---1/3");
+
+    let x = BigRational::from_str("3/4").unwrap();
+    assert_eq!(x, BigRational::new(3, 4));
+    let x = BigRational::from_str("-6/-8").unwrap();
+    assert_eq!(x, BigRational::new(3, 4));
+    let x = BigRational::from_str("-1.25").unwrap();
+    assert_eq!(x, BigRational::new(-5, 4));
+    let x = BigRational::from_str("0.0100").unwrap();
+    assert_eq!(x, BigRational::new(1, 100));
+    let x = BigRational::from_str(".5").unwrap();
+    assert_eq!(x, BigRational::new(1, 2));
+    let x = BigRational::from_str("42").unwrap();
+    assert_eq!(x, 42);
+    assert!(matches!(
+        BigRational::from_str("1/0"),
+        Err(BigIntError::DivisionByZero)
+    ));
+    assert!(matches!(
+        BigRational::from_str("1.2.3"),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(BigRational::from_str("-."), Err(BigIntError::NaN)));
+    assert!(matches!(
+        BigRational::from_str("1/x"),
+        Err(BigIntError::NaN)
+    ));
+}
+
+#[test]
+fn rational_math() {
+    let half = BigRational::new(1, 2);
+    let third = BigRational::new(1, 3);
+    assert_eq!(half.clone() + third.clone(), BigRational::new(5, 6));
+    assert_eq!(half.clone() - third.clone(), BigRational::new(1, 6));
+    assert_eq!(third.clone() - half.clone(), BigRational::new(-1, 6));
+    assert_eq!(half.clone() * third.clone(), BigRational::new(1, 6));
+    assert_eq!(half.clone() / third.clone(), BigRational::new(3, 2));
+    assert_eq!(-half.clone(), BigRational::new(-1, 2));
+    assert_eq!(half.clone() + 1, BigRational::new(3, 2));
+    assert_eq!(third.clone().pow(3), BigRational::new(1, 27));
+    assert_eq!(BigRational::new(-2, 3).pow(-2), BigRational::new(9, 4));
+    assert_eq!(third.recip(), 3);
+    assert_eq!(BigRational::new(-2, 3).abs(), BigRational::new(2, 3));
+
+    let mut x = BigRational::new(1, 6);
+    x += BigRational::new(1, 3);
+    assert_eq!(x, half);
+    x *= 4;
+    assert_eq!(x, 2);
+    x -= BigRational::new(1, 2);
+    x /= 3;
+    assert_eq!(x, half);
+
+    //probability of at least one six in four throws
+    let mut miss = BigRational::from(1);
+    for _ in 0..4 {
+        miss *= BigRational::new(5, 6);
+    }
+    assert_eq!(BigRational::from(1) - miss, BigRational::new(671, 1296));
+
+    assert!(BigRational::new(1, 3) < BigRational::new(1, 2));
+    assert!(BigRational::new(-1, 2) < BigRational::new(-1, 3));
+    assert!(BigRational::new(7, 2) > 3);
+    assert!(BigRational::new(7, 2) < 4_u8);
+    assert_eq!(
+        BigRational::new(2, 3).max(BigRational::new(3, 5)),
+        BigRational::new(2, 3)
+    );
+}
+
+#[test]
+#[should_panic]
+fn rational_zero_denominator() {
+    let _ = BigRational::new(1, 0);
+}
+
+#[test]
+fn rational_rounding() {
+    let x = BigRational::new(7, 2);
+    assert_eq!(x.floor(), 3);
+    assert_eq!(x.ceil(), 4);
+    assert_eq!(x.round(), 4);
+    assert_eq!(x.trunc(), 3);
+    assert_eq!(x.to_integer(), 3);
+
+    let x = BigRational::new(-7, 2);
+    assert_eq!(x.floor(), -4);
+    assert_eq!(x.ceil(), -3);
+    assert_eq!(x.round(), -4);
+    assert_eq!(x.trunc(), -3);
+
+    let x = BigRational::new(-5, 3);
+    assert_eq!(x.floor(), -2);
+    assert_eq!(x.ceil(), -1);
+    assert_eq!(x.round(), -2);
+    assert_eq!(x.trunc(), -1);
+
+    let x = BigRational::new(4, 3);
+    assert_eq!(x.round(), 1);
+    let x = BigRational::from(-5);
+    assert_eq!(x.floor(), -5);
+    assert_eq!(x.ceil(), -5);
+    assert_eq!(x.round(), -5);
+}
+
+#[test]
+fn decimal() {
+    let x = BigDecimal::from_str("123.4500").unwrap();
+    assert_eq!(x.unscaled(), &BigInt::from(1234500));
+    assert_eq!(x.scale(), 4);
+    assert_eq!(format!("{x}"), "123.4500");
+    let x = BigDecimal::from_str("1.2e-7").unwrap();
+    assert_eq!(x, BigDecimal::new(12, 8));
+    assert_eq!(format!("{x}"), "0.00000012");
+    let x = BigDecimal::from_str("-1.5E3").unwrap();
+    assert_eq!(x.scale(), -2);
+    assert_eq!(format!("{x}"), "-1500");
+    let x = BigDecimal::from_str("-.05").unwrap();
+    assert_eq!(format!("{x}"), "-0.05");
+    let x = BigDecimal::from_str("7").unwrap();
+    assert_eq!(format!("{x:+}"), "+7");
+    assert!(matches!(BigDecimal::from_str("+7"), Err(BigIntError::NaN)));
+    let x = BigDecimal::from_str("-0.000").unwrap();
+    assert_eq!(format!("{x}"), "0.000");
+    assert_eq!(format!("{:>8}", BigDecimal::new(-5, 1)), "    -0.5");
+    assert!(matches!(
+        BigDecimal::from_str("1.2.3"),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(BigDecimal::from_str("1e"), Err(BigIntError::NaN)));
+    assert!(matches!(BigDecimal::from_str("."), Err(BigIntError::NaN)));
+    assert!(matches!(BigDecimal::from_str("two"), Err(BigIntError::NaN)));
+    assert!(matches!(
+        BigDecimal::from_str("1e-9223372036854775808"),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        BigDecimal::from_str("1e999999999999"),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        BigDecimal::from_str("1e1000001"),
+        Err(BigIntError::LargeNumber)
+    ));
+    let x = BigDecimal::from_str("1e1000000").unwrap();
+    assert_eq!(x.scale(), -BigDecimal::MAX_SCALE);
+
+    let x = BigDecimal::from_str("1.10").unwrap();
+    let y = BigDecimal::from_str("1.1").unwrap();
+    assert_eq!(x, y);
+    assert_ne!(format!("{x}"), format!("{y}"));
+    assert_eq!(x.normalize().scale(), 1);
+    assert_eq!(
+        BigDecimal::new(1200, 0).normalize(),
+        BigDecimal::new(12, -2)
+    );
+    assert!(BigDecimal::from_str("0.999").unwrap() < BigDecimal::from(1));
+    assert!(BigDecimal::from_str("-2.5").unwrap() < BigDecimal::from_str("-2.49").unwrap());
+    assert_eq!(
+        BigRational::from(BigDecimal::from_str("-1.25").unwrap()),
+        BigRational::new(-5, 4)
+    );
+}
+
+#[test]
+fn decimal_math() {
+    let price = BigDecimal::from_str("19.99").unwrap();
+    let tax = BigDecimal::from_str("0.0825").unwrap();
+    assert_eq!(format!("{}", price.clone() + tax.clone()), "20.0725");
+    assert_eq!(format!("{}", price.clone() - 20), "-0.01");
+    assert_eq!(format!("{}", price.clone() * tax), "1.649175");
+    assert_eq!(format!("{}", -price.clone() * 3), "-59.97");
+
+    let mut total = BigDecimal::from_str("0.10").unwrap();
+    total += BigDecimal::from_str("0.20").unwrap();
+    assert_eq!(format!("{total}"), "0.30");
+    total *= 10;
+    total -= BigDecimal::from_str("3.000").unwrap();
+    assert_eq!(format!("{total}"), "0.000");
+
+    let x = BigDecimal::from_str("170141183460469231731687303715884105727.99").unwrap();
+    assert_eq!(
+        format!("{}", x * 2),
+        "340282366920938463463374607431768211455.98"
+    );
+
+    let one = BigDecimal::from(1);
+    let three = BigDecimal::from(3);
+    assert_eq!(
+        format!("{}", one.divide(&three, 5, RoundingMode::HalfEven)),
+        "0.33333"
+    );
+    assert_eq!(
+        format!("{}", one.divide(&three, 2, RoundingMode::Up)),
+        "0.34"
+    );
+    let x = BigDecimal::from_str("-2").unwrap();
+    assert_eq!(
+        format!("{}", x.divide(&three, 3, RoundingMode::Floor)),
+        "-0.667"
+    );
+    assert_eq!(
+        format!("{}", x.divide(&three, 3, RoundingMode::Down)),
+        "-0.666"
+    );
+    let x = BigDecimal::from_str("1.5e3").unwrap();
+    let y = BigDecimal::from_str("0.04").unwrap();
+    assert_eq!(
+        format!("{}", x.divide(&y, 1, RoundingMode::Down)),
+        "37500.0"
+    );
+    assert_eq!(format!("{}", x.divide(&y, -2, RoundingMode::Down)), "37500");
+}
+
+#[test]
+fn decimal_rounding_modes() {
+    let values = [
+        "5.5", "2.5", "1.6", "1.1", "1.0", "-1.0", "-1.1", "-1.6", "-2.5", "-5.5",
+    ];
+    let expected = [
+        (
+            RoundingMode::Up,
+            ["6", "3", "2", "2", "1", "-1", "-2", "-2", "-3", "-6"],
+        ),
+        (
+            RoundingMode::Down,
+            ["5", "2", "1", "1", "1", "-1", "-1", "-1", "-2", "-5"],
+        ),
+        (
+            RoundingMode::Ceiling,
+            ["6", "3", "2", "2", "1", "-1", "-1", "-1", "-2", "-5"],
+        ),
+        (
+            RoundingMode::Floor,
+            ["5", "2", "1", "1", "1", "-1", "-2", "-2", "-3", "-6"],
+        ),
+        (
+            RoundingMode::HalfUp,
+            ["6", "3", "2", "1", "1", "-1", "-1", "-2", "-3", "-6"],
+        ),
+        (
+            RoundingMode::HalfDown,
+            ["5", "2", "2", "1", "1", "-1", "-1", "-2", "-2", "-5"],
+        ),
+        (
+            RoundingMode::HalfEven,
+            ["6", "2", "2", "1", "1", "-1", "-1", "-2", "-2", "-6"],
+        ),
+    ];
+
+    for (rounding, results) in expected {
+        for (value, result) in values.iter().zip(results) {
+            let x = BigDecimal::from_str(value).unwrap();
+            assert_eq!(format!("{}", x.with_scale(0, rounding)), result);
+        }
+    }
+
+    let x = BigDecimal::from_str("2.675").unwrap();
+    assert_eq!(
+        format!("{}", x.with_scale(2, RoundingMode::HalfEven)),
+        "2.68"
+    );
+    assert_eq!(
+        format!("{}", x.with_scale(5, RoundingMode::HalfEven)),
+        "2.67500"
+    );
+    assert_eq!(format!("{}", x.with_scale(-1, RoundingMode::HalfEven)), "0");
+}
+
+#[test]
+#[should_panic]
+fn decimal_division_by_zero() {
+    let x = BigDecimal::from(1);
+    let _ = x.divide(
+        &BigDecimal::from_str("0.00").unwrap(),
+        2,
+        RoundingMode::HalfEven,
+    );
+}
+
+#[test]
+fn decimal_scale_bounds() {
+    let max = BigDecimal::MAX_SCALE;
+    let x = BigDecimal::new(1, max);
+    let y = BigDecimal::new(1, -max);
+    assert!(x < y);
+    assert_eq!(x.clone() * y.clone(), BigDecimal::from(1));
+    assert_eq!((x.clone() + y.clone()).scale(), max);
+    assert_eq!(x.divide(&y, max, RoundingMode::Down), BigDecimal::from(0));
+
+    //normalize stops at the bound
+    let x = BigDecimal::new(100000, -max)
+        .with_scale(0, RoundingMode::Down)
+        .normalize();
+    assert_eq!(x.scale(), -max);
+    assert_eq!(x.unscaled(), &100000);
+}
+
+#[test]
+#[should_panic(expected = "scale out of range!")]
+fn decimal_new_scale_out_of_range() {
+    let _ = BigDecimal::new(1, i64::MIN);
+}
+
+#[test]
+#[should_panic(expected = "scale out of range!")]
+fn decimal_mul_scale_out_of_range() {
+    let x = BigDecimal::new(1, BigDecimal::MAX_SCALE);
+    let _ = x.clone() * x;
+}
+
+#[test]
+#[should_panic(expected = "scale out of range!")]
+fn decimal_with_scale_out_of_range() {
+    let _ = BigDecimal::from(1).with_scale(i64::MAX, RoundingMode::Down);
+}
+
+#[test]
+#[should_panic(expected = "scale out of range!")]
+fn decimal_divide_scale_out_of_range() {
+    let _ = BigDecimal::from(1).divide(&BigDecimal::from(3), i64::MIN, RoundingMode::Down);
+}
+
+#[test]
+fn float_f64_conversions() {
+    let values = [
+        0.0,
+        1.0,
+        -1.5,
+        0.1,
+        1e300,
+        -2.5e-300,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::from_bits(1),
+        -f64::from_bits(0xfffff),
+        std::f64::consts::PI,
+    ];
+    for value in values {
+        let x = BigFloat::try_from(value).unwrap();
+        assert_eq!(x.to_f64(), value);
+    }
+
+    let x = BigFloat::try_from(0.75).unwrap();
+    assert_eq!(x.mantissa(), &BigInt::from(3));
+    assert_eq!(x.exponent(), -2);
+    assert_eq!(x.precision(), 53);
+    assert!(matches!(
+        BigFloat::try_from(f64::NAN),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(
+        BigFloat::try_from(f64::NEG_INFINITY),
+        Err(BigIntError::LargeNumber)
+    ));
+
+    assert_eq!(BigFloat::new(1, 1024, 10).to_f64(), f64::INFINITY);
+    assert_eq!(BigFloat::new(-1, 1024, 10).to_f64(), f64::NEG_INFINITY);
+    assert_eq!(BigFloat::new(1, -1075, 10).to_f64(), 0.0);
+    assert_eq!(BigFloat::new(3, -1076, 10).to_f64(), f64::from_bits(1));
+    //2^53 + 1 rounds to even
+    let x = BigFloat::from(BigInt::from(2).pow(53) + 1);
+    assert_eq!(x.to_f64(), 9007199254740992.0);
+    let x = BigFloat::from(BigInt::from(2).pow(53) + 3);
+    assert_eq!(x.to_f64(), 9007199254740996.0);
+}
+
+#[test]
+fn float_bigint_conversions() {
+    let x = BigInt::from_str("123456789012345678901234567890").unwrap();
+    let y = BigFloat::from(x.clone());
+    assert_eq!(y.to_bigint(RoundingMode::Down), x);
+    assert_eq!(BigFloat::from(-7).to_bigint(RoundingMode::Down), -7);
+
+    let x = BigFloat::try_from(-2.5).unwrap();
+    assert_eq!(x.to_bigint(RoundingMode::HalfEven), -2);
+    assert_eq!(x.to_bigint(RoundingMode::HalfUp), -3);
+    assert_eq!(x.to_bigint(RoundingMode::Floor), -3);
+    assert_eq!(x.to_bigint(RoundingMode::Ceiling), -2);
+    assert_eq!(x.to_bigint(RoundingMode::Down), -2);
+}
+
+// xorshift, the one generator behind the randomized tests
+fn next_u64(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+// spreads test values over many exponents
+fn next_f64(state: &mut u64) -> f64 {
+    loop {
+        let value = f64::from_bits(next_u64(state) & 0xbfff_ffff_ffff_ffff);
+        if value.is_finite() && value.abs() > 1e-280 {
+            return value;
+        }
+    }
+}
+
+#[test]
+fn float_matches_f64_arithmetic() {
+    let mut state = 0x2545f4914f6cdd1d;
+    for _ in 0..100 {
+        let left = next_f64(&mut state);
+        let right = next_f64(&mut state) * if state % 3 == 0 { 1e-30 } else { 1.0 };
+        let x = BigFloat::try_from(left).unwrap();
+        let y = BigFloat::try_from(right).unwrap();
+
+        assert_eq!((x.clone() + y.clone()).to_f64(), left + right);
+        assert_eq!((x.clone() - y.clone()).to_f64(), left - right);
+        assert_eq!((x.clone() * y.clone()).to_f64(), left * right);
+        assert_eq!((x.clone() / y.clone()).to_f64(), left / right);
+        assert_eq!(x.abs().sqrt().to_f64(), left.abs().sqrt());
+        assert_eq!(x.cmp(&y), left.partial_cmp(&right).unwrap());
+    }
+
+    let x = BigFloat::try_from(1e300).unwrap();
+    let y = BigFloat::try_from(1e-300).unwrap();
+    assert_eq!((x.clone() + y.clone()).to_f64(), 1e300);
+    assert_eq!((x.clone() - y.clone()).to_f64(), 1e300);
+    assert_eq!((y.clone() - x.clone()).to_f64(), -1e300);
+    assert!(x.sub_rounded(&y, 2000, RoundingMode::HalfEven) < x);
+}
+
+#[test]
+fn float_rounding_modes() {
+    let one = BigFloat::from(1);
+    let three = BigFloat::from(3);
+    //1/3 = 0.0101010101...
+    let x = one.div_rounded(&three, 4, RoundingMode::Down);
+    assert_eq!((x.mantissa(), x.exponent()), (&BigInt::from(5), -4));
+    let x = one.div_rounded(&three, 4, RoundingMode::Up);
+    assert_eq!((x.mantissa(), x.exponent()), (&BigInt::from(11), -5));
+    let x = one.div_rounded(&three, 4, RoundingMode::HalfEven);
+    assert_eq!((x.mantissa(), x.exponent()), (&BigInt::from(11), -5));
+    let x = (-one.clone()).div_rounded(&three, 4, RoundingMode::Floor);
+    assert_eq!((x.mantissa(), x.exponent()), (&BigInt::from(-11), -5));
+    let x = (-one.clone()).div_rounded(&three, 4, RoundingMode::Ceiling);
+    assert_eq!((x.mantissa(), x.exponent()), (&BigInt::from(-5), -4));
+
+    //ties
+    let x = BigFloat::from(9);
+    assert_eq!(
+        x.with_precision(3, RoundingMode::HalfEven),
+        BigFloat::from(8)
+    );
+    assert_eq!(
+        x.with_precision(3, RoundingMode::HalfUp),
+        BigFloat::from(10)
+    );
+    assert_eq!(
+        x.with_precision(3, RoundingMode::HalfDown),
+        BigFloat::from(8)
+    );
+    let x = BigFloat::from(11);
+    assert_eq!(
+        x.with_precision(3, RoundingMode::HalfEven),
+        BigFloat::from(12)
+    );
+    assert_eq!(x.with_precision(2, RoundingMode::Up), BigFloat::from(12));
+
+    //exact results are not rounded
+    let x = BigFloat::from(2).sqrt_rounded(8, RoundingMode::Up);
+    assert_eq!(x, BigFloat::new(363, -8, 8));
+    let x = BigFloat::from(144).sqrt_rounded(4, RoundingMode::Up);
+    assert_eq!(x, BigFloat::from(12));
+    let x = BigFloat::new(1, -1000, 1).add_rounded(&one, 10, RoundingMode::Up);
+    assert_eq!(x, BigFloat::new(513, -9, 10));
+    let x = BigFloat::new(1, -1000, 1).add_rounded(&one, 10, RoundingMode::Down);
+    assert_eq!(x, one);
+    let x = BigFloat::new(-1, -1000, 1).add_rounded(&one, 10, RoundingMode::Down);
+    assert_eq!(x, BigFloat::new(1023, -10, 10));
+}
+
+#[test]
+fn float_display() {
+    let x = BigFloat::from(2).sqrt_rounded(200, RoundingMode::HalfEven);
+    assert_eq!(format!("{x:.30}"), "1.41421356237309504880168872421");
+    assert_eq!(format!("{:.5}", -x.clone()), "-1.4142");
+    assert_eq!(format!("{x:+.3}"), "+1.41");
+    assert_eq!(format!("{:.3}", BigFloat::from(1)), "1.00");
+    assert_eq!(format!("{}", BigFloat::try_from(0.1).unwrap()), "0.1");
+    assert_eq!(
+        format!("{}", BigFloat::try_from(-1234.5).unwrap()),
+        "-1234.5"
+    );
+    assert_eq!(format!("{}", BigFloat::try_from(1e-7).unwrap()), "1e-7");
+    assert_eq!(
+        format!("{}", BigFloat::try_from(2.5e-6).unwrap()),
+        "0.0000025"
+    );
+    assert_eq!(format!("{}", BigFloat::try_from(1e21).unwrap()), "1e21");
+    assert_eq!(format!("{:.4}", BigFloat::from(123456)), "123500");
+    assert_eq!(format!("{:.2}", BigFloat::from(999)), "1000");
+    assert_eq!(format!("{:.3}", BigFloat::new(1, 100, 1)), "1.27e30");
+    assert_eq!(format!("{:>6}", BigFloat::zero(10)), "     0");
+}
+
+#[test]
+#[should_panic]
+fn float_division_by_zero() {
+    let _ = BigFloat::from(1) / BigFloat::zero(10);
+}
+
+#[test]
+#[should_panic]
+fn float_negative_sqrt() {
+    let _ = BigFloat::from(-2).sqrt();
+}
+
+#[test]
+fn float_constants() {
+    let x = BigFloat::pi(128);
+    assert_eq!(
+        format!("{x:.38}"),
+        "3.1415926535897932384626433832795028842"
+    );
+    assert_eq!(x.precision(), 128);
+    assert_eq!(BigFloat::pi(53).to_f64(), std::f64::consts::PI);
+    let x = BigFloat::e(128);
+    assert_eq!(
+        format!("{x:.38}"),
+        "2.7182818284590452353602874713526624978"
+    );
+    assert_eq!(BigFloat::e(53).to_f64(), std::f64::consts::E);
+    let x = BigFloat::ln2(128);
+    assert_eq!(format!("{x:.36}"), "0.693147180559945309417232121458176568");
+    assert_eq!(BigFloat::ln2(53).to_f64(), std::f64::consts::LN_2);
+    assert_eq!(
+        BigFloat::pi(1000).with_precision(100, RoundingMode::HalfEven),
+        BigFloat::pi(100)
+    );
+}
+
+#[test]
+fn float_elementary_functions() {
+    let one = BigFloat::from(1);
+    let ten = BigFloat::from(10);
+    assert_eq!(one.exp(128), BigFloat::e(128));
+    assert_eq!(
+        format!("{:.30}", (-ten.clone()).exp(128)),
+        "0.0000453999297624848515355915155606"
+    );
+    assert_eq!(
+        format!("{:.30}", ten.ln(128)),
+        "2.30258509299404568401799145468"
+    );
+    assert_eq!(
+        format!("{:.30}", ten.log2(128)),
+        "3.32192809488736234787031942949"
+    );
+    assert_eq!(
+        format!("{:.30}", one.sin(128)),
+        "0.841470984807896506652502321630"
+    );
+    assert_eq!(
+        format!("{:.30}", one.cos(128)),
+        "0.540302305868139717400936607443"
+    );
+    assert_eq!(
+        format!("{:.30}", BigFloat::from(100).sin(128)),
+        "-0.506365641109758793656557610460"
+    );
+    assert_eq!(
+        one.atan(128),
+        BigFloat::pi(128).with_precision(128, RoundingMode::HalfEven) / 4
+    );
+    assert_eq!(
+        format!(
+            "{:.30}",
+            BigFloat::from(2).pow(&BigFloat::try_from(0.5).unwrap(), 128)
+        ),
+        "1.41421356237309504880168872421"
+    );
+
+    //exact edgecases
+    assert_eq!(one.ln(64), BigFloat::zero(64));
+    assert_eq!(BigFloat::new(1, -20, 1).log2(64), BigFloat::from(-20));
+    assert_eq!(BigFloat::zero(10).exp(64), one);
+    assert_eq!(BigFloat::zero(10).cos(64), one);
+    assert_eq!(BigFloat::zero(10).sin(64), BigFloat::zero(64));
+    assert_eq!(BigFloat::zero(10).atan(64), BigFloat::zero(64));
+    assert_eq!(
+        BigFloat::from(3).pow(&BigFloat::from(40), 64),
+        BigFloat::from(3_u128.pow(40))
+    );
+    assert_eq!(
+        BigFloat::from(-2).pow(&BigFloat::from(-3), 64),
+        BigFloat::try_from(-0.125).unwrap()
+    );
+    assert_eq!(
+        BigFloat::from(4).pow(&BigFloat::try_from(0.5).unwrap(), 64),
+        BigFloat::from(2)
+    );
+    assert_eq!(
+        BigFloat::from(-3).pow(&BigFloat::from(1 << 20), 1).to_f64(),
+        f64::INFINITY
+    );
+
+    //identities
+    let x = BigFloat::try_from(0.3).unwrap();
+    assert_eq!(
+        x.exp(100)
+            .ln(100)
+            .with_precision(80, RoundingMode::HalfEven),
+        x.with_precision(80, RoundingMode::HalfEven)
+    );
+    assert_eq!(
+        BigFloat::try_from(-1e30).unwrap().atan(64),
+        -(BigFloat::pi(64) / 2)
+    );
+    assert_eq!(
+        BigFloat::from(1 << 20).sin(64).to_f64(),
+        f64::sin((1 << 20) as f64)
+    );
+}
+
+#[test]
+fn float_elementary_rounding() {
+    let wide = |value: BigFloat| value.with_precision(53, RoundingMode::HalfEven);
+    let mut state = 0x9e3779b97f4a7c15_u64;
+    for _ in 0..6 {
+        let x = BigFloat::new(next_u64(&mut state) >> 44, -12, 53) - 100;
+        assert_eq!(x.exp(53), wide(x.exp(120)));
+        assert_eq!(x.sin(53), wide(x.sin(120)));
+        assert_eq!(x.cos(53), wide(x.cos(120)));
+        assert_eq!(x.atan(53), wide(x.atan(120)));
+        assert_eq!(x.abs().ln(53), wide(x.abs().ln(120)));
+    }
+
+    //tiny arguments
+    let x = BigFloat::new(3, -300, 2);
+    assert_eq!(x.sin(53), x.with_precision(53, RoundingMode::HalfEven));
+    assert_eq!(x.atan(53), x.with_precision(53, RoundingMode::HalfEven));
+    assert_eq!(x.exp(53), BigFloat::from(1));
+    assert_eq!(x.cos(53), BigFloat::from(1));
+    let x = BigFloat::new((1_u64 << 53) + 1, -53, 60);
+    assert_eq!(format!("{:.5}", x.ln(20)), "1.1102e-16");
+
+    //huge arguments with the result exponent still inside i64
+    let x = BigFloat::new(1, 40, 53);
+    assert_eq!(x.exp(80).ln(53), x);
+    assert_eq!((-x.clone()).exp(80).ln(53), -x);
+}
+
+#[test]
+#[should_panic(expected = "exponent out of range!")]
+fn float_exp_overflow() {
+    let _ = BigFloat::new(1, 70, 53).exp(53);
+}
+
+#[test]
+#[should_panic(expected = "exponent out of range!")]
+fn float_exp_overflow_near_bound() {
+    let _ = BigFloat::new(3, 62, 53).exp(53);
+}
+
+#[test]
+#[should_panic]
+fn float_logarithm_of_negative() {
+    let _ = BigFloat::from(-2).ln(10);
+}
+
+#[test]
+#[should_panic]
+fn float_negative_base_fraction_power() {
+    let _ = BigFloat::from(-2).pow(&BigFloat::try_from(0.5).unwrap(), 10);
+}
+
+#[test]
+fn digits_of_pi_and_e() {
+    assert_eq!(pi_digits(0), 3);
+    assert_eq!(pi_digits(1), 31);
+    assert_eq!(
+        pi_digits(50),
+        "314159265358979323846264338327950288419716939937510"
+    );
+    let x = pi_digits(1000).to_string();
+    assert_eq!(x.len(), 1001);
+    assert_eq!(&x[991..], "2164201989");
+
+    assert_eq!(e_digits(0), 2);
+    assert_eq!(
+        e_digits(50),
+        "271828182845904523536028747135266249775724709369995"
+    );
+    let x = e_digits(1000).to_string();
+    assert_eq!(x.len(), 1001);
+    assert_eq!(&x[991..], "9570350354");
+}
+
+#[test]
+fn large_multiplication_and_division() {
+    let mut x = BigInt::from(123456789);
+    for i in 1..120_u32 {
+        x = x * BigInt::from(7919).pow(i % 37) + i;
+        let y = BigInt::from(10).pow(i % 90) * 999999937 + BigInt::from(-3).pow(i);
+
+        let quotient = x.clone() / y.clone();
+        let remainder = x.clone() % y.clone();
+        assert!(remainder.abs() < y.abs());
+        assert_eq!(quotient * y.clone() + remainder, x);
+        assert_eq!((x.clone() * y.clone()) / x.clone(), y);
+    }
+
+    let x = BigInt::from(10).pow(1000_u32) - 1;
+    assert_eq!(
+        x.clone() * x.clone() + x.clone() * 2 + 1,
+        BigInt::from(10).pow(2000_u32)
+    );
+    assert_eq!(BigInt::from(10).pow(2000_u32) / (x.clone() + 1), x + 1);
+}
+
+// digit by digit schoolbook product, the reference for the limb engine
+fn reference_product(left: &BigUint, right: &BigUint) -> BigUint {
+    let mut digits = vec![0_u32; left.numbers.len() + right.numbers.len()];
+    for (i, &left_digit) in left.numbers.iter().rev().enumerate() {
+        for (j, &right_digit) in right.numbers.iter().rev().enumerate() {
+            digits[i + j] += left_digit as u32 * right_digit as u32;
+        }
+    }
+
+    let mut carry = 0;
+    for digit in digits.iter_mut() {
+        let current = *digit + carry;
+        *digit = current % 10;
+        carry = current / 10;
+    }
+
+    BigUint::from_digits(digits.into_iter().rev().map(|digit| digit as u8).collect())
+}
+
+// exactly `length` random digits
+fn random_biguint(state: &mut u64, length: usize) -> BigUint {
+    let digits = (0..length)
+        .map(|i| (next_u64(state) % if i == 0 { 9 } else { 10 }) as u8 + u8::from(i == 0))
+        .collect();
+    BigUint::from_digits(digits)
+}
+
+fn check_limb_division(dividend: &BigUint, divisor: &BigUint) {
+    let quotient = dividend.clone() / divisor.clone();
+    let remainder = dividend.clone() % divisor.clone();
+    assert!(remainder < *divisor);
+    assert_eq!(reference_product(&quotient, divisor) + remainder, *dividend);
+}
+
+#[test]
+fn limb_engine_matches_schoolbook() {
+    let mut state = 0x5eed_1234_abcd_ef01;
+
+    //9 digits per limb, Karatsuba starts at 48 limbs on both sides
+    for limbs in [1, 2, 47, 48, 49, 95, 96, 97, 150] {
+        for other in [1, 3, 47, 48, 49, 97, 200] {
+            let left = random_biguint(&mut state, limbs * 9 - (limbs % 3));
+            let right = random_biguint(&mut state, other * 9);
+            let product = left.clone() * right.clone();
+            assert_eq!(product, reference_product(&left, &right));
+
+            check_limb_division(&product, &left);
+            check_limb_division(&(product.clone() + right.clone()), &right);
+            if left >= right {
+                check_limb_division(&left, &right);
+            }
+        }
+    }
+
+    //all nines push every carry through
+    let nines = BigUint::from_digits(vec![9; 48 * 9 * 2]);
+    assert_eq!(
+        nines.clone() * nines.clone(),
+        reference_product(&nines, &nines)
+    );
+    check_limb_division(&(nines.clone() * nines.clone()), &nines);
+
+    //the first quotient estimate gets lowered from the second divisor limb
+    check_limb_division(
+        &BigUint::from_str("999999999409314931000000000032845751").unwrap(),
+        &BigUint::from_str("1499999999500000000").unwrap(),
+    );
+    //the estimate is still one too large and the divisor gets added back
+    check_limb_division(
+        &BigUint::from_str("500000001999999999000000000999999998000000000").unwrap(),
+        &BigUint::from_str("500000001999999999999999998").unwrap(),
+    );
+}
+
+#[test]
+fn gaussian_integers() {
+    let x = GaussianInt::new(3, 4);
+    let y = GaussianInt::new(1, -2);
+    assert_eq!(x.clone() + y.clone(), GaussianInt::new(4, 2));
+    assert_eq!(x.clone() - y.clone(), GaussianInt::new(2, 6));
+    assert_eq!(x.clone() * y.clone(), GaussianInt::new(11, -2));
+    assert_eq!(-x.clone(), GaussianInt::new(-3, -4));
+    assert_eq!(x.clone() + 2, GaussianInt::new(5, 4));
+    assert_eq!(GaussianInt::new(0, 1).pow(2), GaussianInt::from(-1));
+    assert_eq!(GaussianInt::new(1, 1).pow(8), GaussianInt::from(16));
+    assert_eq!(x.norm(), 25);
+    assert_eq!(x.conjugate(), GaussianInt::new(3, -4));
+    assert!(GaussianInt::new(0, -1).is_unit());
+    assert!(!GaussianInt::new(1, 1).is_unit());
+
+    assert_eq!(format!("{x}"), "3 + 4i");
+    assert_eq!(format!("{y}"), "1 - 2i");
+    assert_eq!(format!("{}", GaussianInt::new(0, -1)), "-i");
+    assert_eq!(format!("{}", GaussianInt::new(0, 7)), "7i");
+    assert_eq!(format!("{}", GaussianInt::new(-5, 0)), "-5");
+    assert_eq!(format!("{}", GaussianInt::default()), "0");
+
+    let mut z = x.clone();
+    z *= y.clone();
+    z /= y.clone();
+    assert_eq!(z, x);
+    z %= GaussianInt::new(2, 0);
+    assert!(z.norm() < 4);
+}
+
+#[test]
+fn gaussian_division() {
+    let divisors = [
+        GaussianInt::new(2, 3),
+        GaussianInt::new(-7, 1),
+        GaussianInt::new(0, -5),
+        GaussianInt::new(13, 13),
+    ];
+    for re in -12..12 {
+        for im in [-30, -7, 0, 3, 41] {
+            let dividend = GaussianInt::new(re, im);
+            for divisor in &divisors {
+                let (quotient, remainder) = dividend.div_rem(divisor);
+                assert_eq!(quotient * divisor.clone() + remainder.clone(), dividend);
+                assert!(remainder.norm() * 2 <= divisor.norm());
+            }
+        }
+    }
+    assert_eq!(
+        GaussianInt::new(11, -2) / GaussianInt::new(1, -2),
+        GaussianInt::new(3, 4)
+    );
+    assert_eq!(
+        GaussianInt::new(11, -2) % GaussianInt::new(1, -2),
+        GaussianInt::default()
+    );
+}
+
+#[test]
+#[should_panic]
+fn gaussian_division_by_zero() {
+    let _ = GaussianInt::new(1, 1) / GaussianInt::default();
+}
+
+#[test]
+#[should_panic(expected = "negative exponent!")]
+fn gaussian_negative_exponent() {
+    let _ = GaussianInt::new(1, 1).pow(-3);
+}
+
+#[test]
+fn gaussian_gcd_and_factorization() {
+    let x = GaussianInt::new(3, 4) * GaussianInt::new(1, 1) * GaussianInt::new(7, 0);
+    let y = GaussianInt::new(3, 4) * GaussianInt::new(2, -1);
+    assert_eq!(x.gcd(&y), GaussianInt::new(3, 4));
+    assert_eq!(x.gcd(&y).norm(), 25);
+    assert_eq!(GaussianInt::new(0, -3).normalize(), GaussianInt::new(3, 0));
+    assert_eq!(GaussianInt::new(-2, 1).normalize(), GaussianInt::new(1, 2));
+
+    let numbers = [
+        GaussianInt::new(600, -250),
+        GaussianInt::new(-1, 0),
+        GaussianInt::new(0, 2),
+        GaussianInt::new(12345, 6789),
+        GaussianInt::new(21, 0),
+    ];
+    for number in numbers {
+        let (unit, factors) = number.factorize();
+        assert!(unit.is_unit());
+
+        let mut product = unit;
+        for (prime, exponent) in factors {
+            assert_eq!(prime, prime.normalize());
+            //Gaussian primes have a prime norm or are rational primes 3 mod 4
+            let norm = prime.norm();
+            assert!(number_theory::is_probable_prime(&norm) || prime.im == 0);
+            product *= prime.pow(exponent);
+        }
+        assert_eq!(product, number);
+    }
+
+    let (unit, factors) = GaussianInt::new(0, 2).factorize();
+    assert_eq!(unit, GaussianInt::from(1));
+    assert_eq!(factors, vec![(GaussianInt::new(1, 1), 2)]);
+    let (_, factors) = GaussianInt::from(21).factorize();
+    assert_eq!(
+        factors,
+        vec![(GaussianInt::from(3), 1), (GaussianInt::from(7), 1)]
+    );
+}
+
+#[test]
+fn sum_of_two_squares() {
+    for number in 0..200 {
+        let number = BigInt::from(number);
+        match GaussianInt::sum_of_two_squares(&number) {
+            Some((a, b)) => {
+                assert!(a <= b);
+                assert_eq!(a.clone() * a + b.clone() * b, number);
+            }
+            None => {
+                let representable = (0..15).any(|a| (0..15).any(|b| number == a * a + b * b));
+                assert!(!representable);
+            }
+        }
+    }
+
+    let prime = BigInt::from_str("1000000000000000000000000000057").unwrap();
+    let (a, b) = GaussianInt::sum_of_two_squares(&prime).unwrap();
+    assert_eq!(a.clone() * a + b.clone() * b, prime);
+    assert_eq!(GaussianInt::sum_of_two_squares(&BigInt::from(-5)), None);
+    assert_eq!(GaussianInt::sum_of_two_squares(&BigInt::from(21)), None);
+}
+
+#[test]
+fn polynomial() {
+    let p = Polynomial::new(vec![7, -1, 3]);
+    assert_eq!(format!("{p}"), "3x^2 - x + 7");
+    assert_eq!(format!("{}", -p.clone()), "-3x^2 + x - 7");
+    assert_eq!(format!("{}", Polynomial::new(vec![0, 1])), "x");
+    assert_eq!(format!("{}", Polynomial::monomial(-2, 5)), "-2x^5");
+    assert_eq!(format!("{}", Polynomial::new(vec![0, 0, 0])), "0");
+    assert_eq!(format!("{:>8}", Polynomial::new(vec![1, 1])), "   x + 1");
+
+    assert_eq!(p.degree(), Some(2));
+    assert_eq!(Polynomial::default().degree(), None);
+    assert_eq!(p.leading_coefficient(), 3);
+    assert_eq!(
+        p.coefficients(),
+        &[BigInt::from(7), BigInt::from(-1), BigInt::from(3)]
+    );
+    assert_eq!(p.evaluate(0), 7);
+    assert_eq!(p.evaluate(-2), 21);
+    assert_eq!(
+        p.evaluate(BigInt::from_str("100000000000").unwrap()),
+        BigInt::from_str("29999999999900000000007").unwrap()
+    );
+    assert_eq!(p.derivative(), Polynomial::new(vec![-1, 6]));
+    assert_eq!(Polynomial::from(5).derivative(), Polynomial::default());
+
+    let q = Polynomial::new(vec![1, 1]);
+    assert_eq!(p.clone() + q.clone(), Polynomial::new(vec![8, 0, 3]));
+    assert_eq!(p.clone() - p.clone(), Polynomial::default());
+    assert_eq!(p.clone() * q.clone(), Polynomial::new(vec![7, 6, 2, 3]));
+    assert_eq!(p.clone() * 2, Polynomial::new(vec![14, -2, 6]));
+
+    let mut r = p.clone();
+    r += 1;
+    r *= q.clone();
+    r -= q;
+    assert_eq!(r, Polynomial::new(vec![7, 6, 2, 3]));
+}
+
+#[test]
+fn polynomial_karatsuba() {
+    let left = Polynomial::new((0..100).map(|i| i * i - 1234).collect());
+    let right = Polynomial::new((0..37).map(|i| 99 - 7 * i).collect());
+    let product = left.clone() * right.clone();
+    assert_eq!(product.degree(), Some(135));
+    for x in [-3, -1, 0, 1, 2, 10] {
+        assert_eq!(product.evaluate(x), left.evaluate(x) * right.evaluate(x));
+    }
+
+    //(x + 1)^64 through repeated squaring
+    let mut power = Polynomial::new(vec![1, 1]);
+    for _ in 0..6 {
+        power = power.clone() * power;
+    }
+    assert_eq!(power.coefficients()[32], BigInt::binomial(64, 32));
+    assert_eq!(power.evaluate(1), BigInt::from(2).pow(64));
+}
+
+#[test]
+fn polynomial_division_and_gcd() {
+    let a = Polynomial::new(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+    let b = Polynomial::new(vec![21, -9, -4, 0, 5, 0, 3]);
+    let (quotient, remainder) = a.pseudo_div_rem(&b);
+    assert_eq!(
+        a.clone() * BigInt::from(3).pow(3),
+        quotient * b.clone() + remainder.clone()
+    );
+    assert!(remainder.degree() < b.degree());
+    assert_eq!(a.pseudo_rem(&b), remainder);
+    assert_eq!(a.gcd(&b), Polynomial::from(1));
+
+    let small = Polynomial::new(vec![1, 2]);
+    assert_eq!(small.pseudo_div_rem(&a), (Polynomial::default(), small));
+
+    assert_eq!(Polynomial::new(vec![6, -4, 10]).content(), 2);
+    assert_eq!(Polynomial::new(vec![6, -4, -10]).content(), -2);
+    assert_eq!(
+        Polynomial::new(vec![6, -4, -10]).primitive_part(),
+        Polynomial::new(vec![-3, 2, 5])
+    );
+
+    //(2x + 1)(x - 3) * 6 and (2x + 1)(x^2 + 1) * 4
+    let common = Polynomial::new(vec![1, 2]);
+    let left = common.clone() * Polynomial::new(vec![-3, 1]) * 6;
+    let right = common.clone() * Polynomial::new(vec![1, 0, 1]) * -4;
+    assert_eq!(left.gcd(&right), common.clone() * 2);
+    assert_eq!(right.gcd(&left), common.clone() * 2);
+    assert_eq!(left.gcd(&Polynomial::default()), left);
+    assert_eq!(Polynomial::default().gcd(&right), -right.clone());
+
+    let repeated = Polynomial::new(vec![-1, 1]).pow(5) * Polynomial::new(vec![2, 0, 1]);
+    assert_eq!(
+        repeated.gcd(&repeated.derivative()),
+        Polynomial::new(vec![-1, 1]).pow(4)
+    );
+}
+
+#[test]
+#[should_panic]
+fn polynomial_division_by_zero() {
+    let _ = Polynomial::new(vec![1, 2]).pseudo_div_rem(&Polynomial::default());
+}
+
+#[test]
+#[should_panic(expected = "negative exponent!")]
+fn polynomial_negative_exponent() {
+    let _ = Polynomial::new(vec![1, 2]).pow(-2);
+}
+
+#[test]
+fn matrix() {
+    let a: Matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let b: Matrix = Matrix::new(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+    assert_eq!((a.rows(), a.cols()), (2, 3));
+    assert_eq!(a[(1, 2)], 6);
+    assert_eq!(
+        a.row(0),
+        &[BigInt::from(1), BigInt::from(2), BigInt::from(3)]
+    );
+    assert_eq!(a.transpose().transpose(), a);
+    assert_eq!(a.transpose()[(2, 0)], 3);
+    assert_eq!(format!("{a}"), "[1, 2, 3]\n[4, 5, 6]");
+
+    let product = a.clone() * b.clone();
+    assert_eq!(product, Matrix::new(vec![vec![58, 64], vec![139, 154]]));
+    assert_eq!(product.clone() * Matrix::identity(2), product);
+    assert_eq!(a.clone() + a.clone() - a.clone(), a);
+    assert_eq!(-a.clone() + a.clone(), Matrix::zeros(2, 3));
+
+    let mut c = product.clone();
+    c += Matrix::identity(2);
+    c -= product;
+    c *= Matrix::new(vec![vec![2, 0], vec![0, 3]]);
+    assert_eq!(c, Matrix::new(vec![vec![2, 0], vec![0, 3]]));
+    c[(0, 1)] = BigInt::from(-1);
+    assert_eq!(format!("{c}"), "[2, -1]\n[0, 3]");
+}
+
+#[test]
+fn matrix_determinant_and_rank() {
+    let a: Matrix = Matrix::new(vec![vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+    assert_eq!(a.determinant(), 49);
+    assert_eq!(a.rank(), 3);
+
+    //a zero pivot forces a row swap
+    let b: Matrix = Matrix::new(vec![vec![0, 1], vec![1, 0]]);
+    assert_eq!(b.determinant(), -1);
+
+    let singular: Matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    assert_eq!(singular.determinant(), 0);
+    assert_eq!(singular.rank(), 2);
+    assert_eq!(Matrix::<BigInt>::zeros(3, 5).rank(), 0);
+    assert_eq!(
+        Matrix::new(vec![vec![0, 0, 1, 2], vec![0, 0, 2, 4], vec![0, 1, 0, 0]]).rank(),
+        2
+    );
+    assert_eq!(Matrix::<BigInt>::identity(0).determinant(), 1);
+
+    //the symmetric Pascal matrix has determinant 1
+    let n = 30;
+    let pascal: Matrix = Matrix::new(
+        (0..n)
+            .map(|i| (0..n).map(|j| BigInt::binomial(i + j, i)).collect())
+            .collect::<Vec<Vec<BigInt>>>(),
+    );
+    assert_eq!(pascal.determinant(), 1);
+
+    //Vandermonde determinant is the product of differences
+    let points = [3, -1, 4, 10, 5, -9, 2, 6];
+    let vandermonde: Matrix = Matrix::new(
+        points
+            .iter()
+            .map(|&x| {
+                (0..points.len() as u32)
+                    .map(|k| BigInt::from(x).pow(k))
+                    .collect()
+            })
+            .collect::<Vec<Vec<BigInt>>>(),
+    );
+    let mut expected = BigInt::from(1);
+    for j in 0..points.len() {
+        for i in 0..j {
+            expected *= points[j] - points[i];
+        }
+    }
+    assert_eq!(vandermonde.determinant(), expected);
+}
+
+#[test]
+fn matrix_inverse_and_adjugate() {
+    let a: Matrix = Matrix::new(vec![vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+    let inverse = a.inverse().unwrap();
+    assert_eq!(inverse[(0, 0)], BigRational::new(4, 49));
+    assert_eq!(
+        Matrix::<BigRational>::from(a.clone()) * inverse,
+        Matrix::identity(3)
+    );
+
+    let adjugate = a.adjugate();
+    assert_eq!(
+        a.clone() * adjugate.clone(),
+        Matrix::new(vec![vec![49, 0, 0], vec![0, 49, 0], vec![0, 0, 49]])
+    );
+    assert_eq!(adjugate[(0, 0)], 4);
+
+    let singular: Matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    assert_eq!(singular.inverse(), None);
+    assert_eq!(
+        singular.adjugate(),
+        Matrix::new(vec![vec![-3, 6, -3], vec![6, -12, 6], vec![-3, 6, -3]])
+    );
+    assert_eq!(singular.clone() * singular.adjugate(), Matrix::zeros(3, 3));
+    assert_eq!(
+        Matrix::<BigInt>::zeros(3, 3).adjugate(),
+        Matrix::zeros(3, 3)
+    );
+    assert_eq!(
+        Matrix::<BigInt>::zeros(1, 1).adjugate(),
+        Matrix::identity(1)
+    );
+}
+
+#[test]
+fn matrix_solve() {
+    let a: Matrix = Matrix::new(vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]]);
+    let rhs = [BigInt::from(8), BigInt::from(-11), BigInt::from(-3)];
+    assert_eq!(
+        a.solve(&rhs),
+        Some(vec![
+            BigRational::from(2),
+            BigRational::from(3),
+            BigRational::from(-1)
+        ])
+    );
+
+    let b: Matrix = Matrix::new(vec![vec![3, 1], vec![1, 2]]);
+    assert_eq!(
+        b.solve(&[BigInt::from(1), BigInt::from(0)]),
+        Some(vec![BigRational::new(2, 5), BigRational::new(-1, 5)])
+    );
+
+    //underdetermined and inconsistent systems
+    let c: Matrix = Matrix::new(vec![vec![1, 2, 3], vec![2, 4, 6]]);
+    let rhs = [BigInt::from(6), BigInt::from(12)];
+    let solution = c.solve(&rhs).unwrap();
+    assert_eq!(
+        solution,
+        vec![
+            BigRational::from(6),
+            BigRational::from(0),
+            BigRational::from(0)
+        ]
+    );
+    assert_eq!(c.solve(&[BigInt::from(6), BigInt::from(13)]), None);
+
+    let d: Matrix = Matrix::new(vec![vec![1, 1], vec![1, -1], vec![2, 0]]);
+    assert_eq!(
+        d.solve(&[BigInt::from(3), BigInt::from(1), BigInt::from(4)]),
+        Some(vec![BigRational::from(2), BigRational::from(1)])
+    );
+}
+
+#[test]
+#[should_panic]
+fn matrix_dimension_mismatch() {
+    let a: Matrix = Matrix::new(vec![vec![1, 2, 3]]);
+    let _ = a.clone() * a;
+}
+
+#[test]
+fn hermite_normal_form() {
+    let a: Matrix = Matrix::new(vec![vec![2, 3, 6, 2], vec![5, 6, 1, 6], vec![8, 3, 1, 1]]);
+    let (h, u) = a.hermite_normal_form();
+    assert_eq!(
+        h,
+        Matrix::new(vec![
+            vec![1, 0, 50, -11],
+            vec![0, 3, 28, -2],
+            vec![0, 0, 61, -13]
+        ])
+    );
+    assert_eq!(u.clone() * a.clone(), h);
+    assert_eq!(u.determinant().abs(), 1);
+
+    //rank deficient with a zero column
+    let b: Matrix = Matrix::new(vec![
+        vec![0, 4, -6, 10],
+        vec![0, -6, 9, -15],
+        vec![0, 2, 1, 3],
+        vec![0, 8, -12, 20],
+    ]);
+    let (h, u) = b.hermite_normal_form();
+    assert_eq!(u.clone() * b.clone(), h);
+    assert_eq!(u.determinant().abs(), 1);
+    assert_eq!(h.rank(), b.rank());
+    assert_eq!(h.row(0), &[0, 2, 1, 3].map(BigInt::from));
+    assert_eq!(h.row(1), &[0, 0, 4, -2].map(BigInt::from));
+    assert_eq!(h.row(2), &[0, 0, 0, 0].map(BigInt::from));
+
+    //no rows edgecase keeps the column count
+    let empty = Matrix::<BigInt>::zeros(0, 3);
+    let (h, u) = empty.hermite_normal_form();
+    assert_eq!((h.rows(), h.cols()), (0, 3));
+    assert_eq!((u.rows(), u.cols()), (0, 0));
+}
+
+#[test]
+fn smith_normal_form() {
+    let a: Matrix = Matrix::new(vec![vec![2, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]]);
+    let (s, u, v) = a.smith_normal_form();
+    assert_eq!(
+        s,
+        Matrix::new(vec![vec![2, 0, 0], vec![0, 6, 0], vec![0, 0, 12]])
+    );
+    assert_eq!(u.clone() * a.clone() * v.clone(), s);
+    assert_eq!(u.determinant().abs(), 1);
+    assert_eq!(v.determinant().abs(), 1);
+
+    //Z^2 / <(2, 0), (0, 3), (4, 6)> is Z/6
+    let b: Matrix = Matrix::new(vec![vec![2, 0], vec![0, 3], vec![4, 6]]);
+    let (s, u, v) = b.smith_normal_form();
+    assert_eq!(s, Matrix::new(vec![vec![1, 0], vec![0, 6], vec![0, 0]]));
+    assert_eq!(u.clone() * b.clone() * v.clone(), s);
+    assert_eq!(u.determinant().abs(), 1);
+    assert_eq!(v.determinant().abs(), 1);
+
+    let zero = Matrix::<BigInt>::zeros(2, 3);
+    let (s, u, v) = zero.smith_normal_form();
+    assert_eq!(s, zero);
+    assert_eq!((u, v), (Matrix::identity(2), Matrix::identity(3)));
+
+    let empty = Matrix::<BigInt>::zeros(0, 3);
+    let (s, u, v) = empty.smith_normal_form();
+    assert_eq!(s, empty);
+    assert_eq!((u, v), (Matrix::identity(0), Matrix::identity(3)));
+
+    //entries grow well beyond i128 along the way
+    let c: Matrix = Matrix::new(
+        (0..6)
+            .map(|i| {
+                (0..5)
+                    .map(|j| BigInt::from(3 + i * 7 + j * j).pow(12))
+                    .collect()
+            })
+            .collect::<Vec<Vec<BigInt>>>(),
+    );
+    let (s, u, v) = c.smith_normal_form();
+    assert_eq!(u.clone() * c.clone() * v.clone(), s);
+    assert_eq!(u.determinant().abs(), 1);
+    assert_eq!(v.determinant().abs(), 1);
+    for i in 0..5 {
+        for j in 0..5 {
+            if i != j {
+                assert_eq!(s[(i, j)], 0);
+            }
+        }
+        assert!(s[(i, i)] >= 0);
+        if i < 4 && s[(i, i)] != 0 {
+            assert_eq!(s[(i + 1, i + 1)].clone() % s[(i, i)].clone(), 0);
+        }
+    }
+}
+
+#[test]
+fn lll_reduction() {
+    let basis: Matrix = Matrix::new(vec![vec![1, 1, 1], vec![-1, 0, 2], vec![3, 5, 6]]);
+    let reduced = basis.lll(&BigRational::new(3, 4));
+    assert_eq!(
+        reduced,
+        Matrix::new(vec![vec![0, 1, 0], vec![1, 0, 1], vec![-1, 0, 2]])
+    );
+    assert_eq!(reduced.determinant().abs(), basis.determinant().abs());
+
+    //knapsack lattice with a planted short vector
+    let weights = [
+        "366998615094981476553071",
+        "548014651309453016720497",
+        "277283590733434211093887",
+        "851004498170119049738961",
+        "405817236812211380541983",
+        "192005377512409381773029",
+        "731129384670018763203779",
+        "623380931822096458911653",
+    ]
+    .map(|w| BigInt::from_str(w).unwrap());
+    let chosen = [1, 0, 1, 1, 0, 0, 1, 0];
+    let target = weights
+        .iter()
+        .zip(chosen)
+        .fold(BigInt::default(), |sum, (w, c)| sum + w.clone() * c);
+
+    let mut rows: Vec<Vec<BigInt>> = (0..8)
+        .map(|i| {
+            let mut row: Vec<BigInt> = (0..8).map(|j| BigInt::from(2 * u8::from(i == j))).collect();
+            row.push(weights[i].clone());
+            row
+        })
+        .collect();
+    let mut last = vec![BigInt::from(1); 8];
+    last.push(target);
+    rows.push(last);
+
+    let reduced = Matrix::new(rows).lll(&BigRational::new(99, 100));
+    let found = (0..reduced.rows()).any(|i| {
+        let row = reduced.row(i);
+        row[8] == 0
+            && (row[..8] == chosen.map(|c| BigInt::from(1 - 2 * c))
+                || row[..8] == chosen.map(|c| BigInt::from(2 * c - 1)))
+    });
+    assert!(found);
+}
+
+#[test]
+fn integer_relations() {
+    let ln2 = BigFloat::from(2).ln(200);
+    let ln3 = BigFloat::from(3).ln(200);
+    let ln6 = BigFloat::from(6).ln(200);
+    assert_eq!(
+        integer_relation(&[ln2, ln3, ln6], 180),
+        Some(vec![BigInt::from(1), BigInt::from(1), BigInt::from(-1)])
+    );
+
+    //1 + sqrt(2) is a root of x^2 - 2x - 1
+    let x = BigFloat::from(2).sqrt_rounded(200, RoundingMode::HalfEven) + BigFloat::from(1);
+    let powers = [BigFloat::new(1, 0, 200), x.clone(), x.clone() * x];
+    assert_eq!(
+        integer_relation(&powers, 180),
+        Some(vec![BigInt::from(1), BigInt::from(2), BigInt::from(-1)])
+    );
+
+    //pi and e have no small relation
+    let values = [
+        BigFloat::pi(200),
+        BigFloat::e(200),
+        BigFloat::new(1, 0, 200),
+    ];
+    assert_eq!(integer_relation(&values, 180), None);
+}
+
+#[test]
+#[should_panic]
+fn lll_dependent_basis() {
+    let basis: Matrix = Matrix::new(vec![vec![1, 2], vec![2, 4]]);
+    let _ = basis.lll(&BigRational::new(3, 4));
+}
+
+#[test]
+fn linear_diophantine_equations() {
+    let solution =
+        linear_diophantine(&BigInt::from(6), &BigInt::from(10), &BigInt::from(8)).unwrap();
+    assert_eq!(
+        solution,
+        LinearDiophantine {
+            x: BigInt::from(3),
+            y: BigInt::from(-1),
+            x_step: BigInt::from(5),
+            y_step: BigInt::from(-3),
+        }
+    );
+    for t in -5..5 {
+        let (x, y) = solution.solution(t);
+        assert_eq!(x * 6 + y * 10, 8);
+    }
+
+    assert_eq!(
+        linear_diophantine(&BigInt::from(6), &BigInt::from(10), &BigInt::from(7)),
+        None
+    );
+
+    let solution =
+        linear_diophantine(&BigInt::from(0), &BigInt::from(5), &BigInt::from(15)).unwrap();
+    assert_eq!(solution.solution(0), (BigInt::from(0), BigInt::from(3)));
+    assert_eq!(
+        (solution.x_step, solution.y_step),
+        (BigInt::from(1), BigInt::from(0))
+    );
+
+    let solution =
+        linear_diophantine(&BigInt::from(4), &BigInt::from(0), &BigInt::from(-8)).unwrap();
+    assert_eq!(solution.solution(0), (BigInt::from(-2), BigInt::from(0)));
+    assert_eq!(
+        (solution.x_step, solution.y_step),
+        (BigInt::from(0), BigInt::from(1))
+    );
+
+    let a = BigInt::from_str("-123456789012345678901234567890").unwrap();
+    let b = BigInt::from_str("98765432109876543210987").unwrap();
+    let c = BigInt::from_str("1000000000000000000000000000000000000000").unwrap() * 3;
+    let solution = linear_diophantine(&a, &b, &c).unwrap();
+    assert!(solution.x >= 0 && solution.x < solution.x_step);
+    for t in [-1000, 0, 7] {
+        let (x, y) = solution.solution(t);
+        assert_eq!(a.clone() * x + b.clone() * y, c);
+    }
+}
+
+#[test]
+#[should_panic]
+fn linear_diophantine_zero_coefficients() {
+    let _ = linear_diophantine(&BigInt::from(0), &BigInt::from(0), &BigInt::from(1));
+}
+
+#[test]
+fn linear_diophantine_systems() {
+    let a: Matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let rhs = [BigInt::from(6), BigInt::from(15)];
+    let (solution, kernel) = linear_diophantine_system(&a, &rhs).unwrap();
+    let column = |v: &[BigInt]| Matrix::new(v.iter().map(|x| vec![x.clone()]).collect());
+    assert_eq!(a.clone() * column(&solution), column(&rhs));
+    assert_eq!(kernel.len(), 1);
+    assert_eq!(a.clone() * column(&kernel[0]), Matrix::zeros(2, 1));
+    assert!(
+        kernel[0] == [1, -2, 1].map(BigInt::from) || kernel[0] == [-1, 2, -1].map(BigInt::from)
+    );
+
+    //rational solutions that aren't integral
+    let b: Matrix = Matrix::new(vec![vec![2, 4], vec![0, 2]]);
+    assert_eq!(
+        linear_diophantine_system(&b, &[BigInt::from(2), BigInt::from(1)]),
+        None
+    );
+    assert_eq!(
+        linear_diophantine_system(&b, &[BigInt::from(2), BigInt::from(2)]),
+        Some((vec![BigInt::from(-1), BigInt::from(1)], vec![]))
+    );
+
+    //inconsistent
+    let c: Matrix = Matrix::new(vec![vec![1, 1], vec![2, 2]]);
+    assert_eq!(
+        linear_diophantine_system(&c, &[BigInt::from(1), BigInt::from(3)]),
+        None
+    );
+
+    //6x + 10y + 15z = 1 has a two dimensional kernel
+    let d: Matrix = Matrix::new(vec![vec![6, 10, 15]]);
+    let (solution, kernel) = linear_diophantine_system(&d, &[BigInt::from(1)]).unwrap();
+    assert_eq!(d.clone() * column(&solution), Matrix::new(vec![vec![1]]));
+    assert_eq!(kernel.len(), 2);
+    assert_eq!(Matrix::<BigInt>::new(kernel.clone()).rank(), 2);
+    for vector in kernel {
+        assert_eq!(d.clone() * column(&vector), Matrix::zeros(1, 1));
+    }
+}
+
+#[test]
+fn pell_equation() {
+    let solutions: Vec<(BigInt, BigInt)> = Pell::new(&BigInt::from(2)).unwrap().take(3).collect();
+    assert_eq!(
+        solutions,
+        [(3, 2), (17, 12), (99, 70)].map(|(x, y)| (BigInt::from(x), BigInt::from(y)))
+    );
+    let solutions: Vec<(BigInt, BigInt)> =
+        Pell::negative(&BigInt::from(2)).unwrap().take(3).collect();
+    assert_eq!(
+        solutions,
+        [(1, 1), (7, 5), (41, 29)].map(|(x, y)| (BigInt::from(x), BigInt::from(y)))
+    );
+
+    assert_eq!(
+        Pell::new(&BigInt::from(61)).unwrap().fundamental(),
+        (BigInt::from(1766319049), BigInt::from(226153980))
+    );
+    assert_eq!(
+        Pell::negative(&BigInt::from(13)).unwrap().fundamental(),
+        (BigInt::from(18), BigInt::from(5))
+    );
+    assert!(Pell::negative(&BigInt::from(3)).is_none());
+    assert!(Pell::new(&BigInt::from(16)).is_none());
+    assert!(Pell::new(&BigInt::from(0)).is_none());
+    assert!(Pell::new(&BigInt::from(-2)).is_none());
+
+    let d = BigInt::from(991);
+    let (x, y) = Pell::new(&d).unwrap().fundamental();
+    assert_eq!(
+        x,
+        BigInt::from_str("379516400906811930638014896080").unwrap()
+    );
+    for (x, y) in Pell::new(&d).unwrap().take(5) {
+        assert_eq!(x.clone() * x - d.clone() * y.clone() * y, 1);
+    }
+    assert_eq!(
+        y,
+        BigInt::from_str("12055735790331359447442538767").unwrap()
+    );
+}
+
+#[test]
+fn continued_fractions() {
+    let to_big = |values: &[i64]| {
+        values
+            .iter()
+            .map(|&v| BigInt::from(v))
+            .collect::<Vec<BigInt>>()
+    };
+
+    assert_eq!(
+        continued_fraction(&BigInt::from(415), &BigInt::from(93)),
+        to_big(&[4, 2, 6, 7])
+    );
+    assert_eq!(
+        continued_fraction(&BigInt::from(-415), &BigInt::from(93)),
+        to_big(&[-5, 1, 1, 6, 7])
+    );
+    assert_eq!(
+        continued_fraction(&BigInt::from(415), &BigInt::from(-93)),
+        to_big(&[-5, 1, 1, 6, 7])
+    );
+    assert_eq!(
+        continued_fraction(&BigInt::from(0), &BigInt::from(7)),
+        to_big(&[0])
+    );
+    assert_eq!(
+        continued_fraction(&BigInt::from(12), &BigInt::from(4)),
+        to_big(&[3])
+    );
+
+    assert_eq!(
+        from_continued_fraction(&to_big(&[4, 2, 6, 7])),
+        (BigInt::from(415), BigInt::from(93))
+    );
+    assert_eq!(
+        from_continued_fraction(&to_big(&[-5, 1, 1, 6, 7])),
+        (BigInt::from(-415), BigInt::from(93))
+    );
+
+    //round trip of consecutive Fibonacci numbers gives only ones
+    let p = BigInt::fibonacci(300);
+    let q = BigInt::fibonacci(299);
+    let quotients = continued_fraction(&p, &q);
+    assert_eq!(quotients.len(), 298);
+    assert!(quotients[..297].iter().all(|a| *a == 1));
+    assert_eq!(from_continued_fraction(&quotients), (p, q));
+
+    let convergents: Vec<(BigInt, BigInt)> = Convergents::new(to_big(&[4, 2, 6, 7])).collect();
+    assert_eq!(
+        convergents,
+        [(4, 1), (9, 2), (58, 13), (415, 93)].map(|(p, q)| (BigInt::from(p), BigInt::from(q)))
+    );
+}
+
+#[test]
+fn sqrt_continued_fractions() {
+    assert_eq!(
+        sqrt_continued_fraction(&BigInt::from(2)),
+        (BigInt::from(1), vec![BigInt::from(2)])
+    );
+    assert_eq!(
+        sqrt_continued_fraction(&BigInt::from(14)),
+        (BigInt::from(3), [1, 2, 1, 6].map(BigInt::from).to_vec())
+    );
+    assert_eq!(
+        sqrt_continued_fraction(&BigInt::from(49)),
+        (BigInt::from(7), vec![])
+    );
+    assert_eq!(
+        sqrt_continued_fraction(&BigInt::from(0)),
+        (BigInt::from(0), vec![])
+    );
+    let (_, period) = sqrt_continued_fraction(&BigInt::from(94));
+    assert_eq!(period.len(), 16);
+
+    //convergents of the periodic expansion approximate sqrt(2) from alternating sides
+    let (root, period) = sqrt_continued_fraction(&BigInt::from(2));
+    let quotients = std::iter::once(root).chain(period.into_iter().cycle());
+    let (p, q) = Convergents::new(quotients).nth(100).unwrap();
+    let difference = p.clone() * p - q.clone() * q * 2;
+    assert!(difference == 1 || difference == -1);
+}
+
+#[test]
+#[should_panic]
+fn continued_fraction_division_by_zero() {
+    let _ = continued_fraction(&BigInt::from(1), &BigInt::from(0));
+}
+
+#[test]
+#[should_panic(expected = "non-positive partial quotient!")]
+fn from_continued_fraction_zero_quotient() {
+    let _ = from_continued_fraction(&[BigInt::from(0), BigInt::from(0)]);
+}
+
+// random limbs with a random number of leading zero limbs
+fn next_limbs<const LIMBS: usize>(state: &mut u64) -> [u64; LIMBS] {
+    let used = next_u64(state) as usize % (LIMBS + 1);
+    let mut limbs = [0; LIMBS];
+    for limb in limbs.iter_mut().take(used) {
+        *limb = next_u64(state);
+    }
+    if next_u64(state).is_multiple_of(8) {
+        limbs[LIMBS - 1] = u64::MAX;
+    }
+    limbs
+}
+
+#[test]
+fn fixed_width_matches_primitives() {
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    let to_u128 = |x: Uint<2>| x.limbs()[0] as u128 | (x.limbs()[1] as u128) << 64;
+    let to_i128 = |x: Int<2>| to_u128(x.cast_unsigned()) as i128;
+
+    for _ in 0..2000 {
+        let a = Uint::<2>::from_limbs(next_limbs(&mut state));
+        let b = Uint::<2>::from_limbs(next_limbs(&mut state));
+        let (x, y) = (to_u128(a), to_u128(b));
+        let shift = (next_u64(&mut state) % 160) as u32;
+        let exponent = (next_u64(&mut state) % 6) as u32;
+
+        assert_eq!(
+            a.overflowing_add(b),
+            (Uint::from(x.wrapping_add(y)), x.overflowing_add(y).1)
+        );
+        assert_eq!(
+            a.overflowing_sub(b),
+            (Uint::from(x.wrapping_sub(y)), x.overflowing_sub(y).1)
+        );
+        assert_eq!(
+            a.overflowing_mul(b),
+            (Uint::from(x.wrapping_mul(y)), x.overflowing_mul(y).1)
+        );
+        assert_eq!(
+            a.overflowing_pow(exponent),
+            (
+                Uint::from(x.wrapping_pow(exponent)),
+                x.overflowing_pow(exponent).1
+            )
+        );
+        assert_eq!(
+            a.overflowing_shl(shift),
+            (
+                Uint::from(x.wrapping_shl(shift)),
+                x.overflowing_shl(shift).1
+            )
+        );
+        assert_eq!(
+            a.overflowing_shr(shift),
+            (
+                Uint::from(x.wrapping_shr(shift)),
+                x.overflowing_shr(shift).1
+            )
+        );
+        assert_eq!(a.checked_div(b).map(to_u128), x.checked_div(y));
+        assert_eq!(a.checked_rem(b).map(to_u128), x.checked_rem(y));
+        assert_eq!(to_u128(a.saturating_add(b)), x.saturating_add(y));
+        assert_eq!(to_u128(a.saturating_sub(b)), x.saturating_sub(y));
+        assert_eq!(to_u128(a.saturating_mul(b)), x.saturating_mul(y));
+        assert_eq!(to_u128(a & b | !a ^ b), x & y | !x ^ y);
+        assert_eq!(a.leading_zeros(), x.leading_zeros());
+        assert_eq!(a.trailing_zeros(), x.trailing_zeros());
+        assert_eq!(a.cmp(&b), x.cmp(&y));
+        assert_eq!(a.to_string(), x.to_string());
+        assert_eq!(format!("{a:x}"), format!("{x:x}"));
+        assert_eq!(format!("{a:#b}"), format!("{x:#b}"));
+
+        let (a, b) = (a.cast_signed(), b.cast_signed());
+        let (x, y) = (to_i128(a), to_i128(b));
+        let from = |value: i128| Int::<2>::from(value);
+
+        assert_eq!(
+            a.overflowing_add(b),
+            (from(x.wrapping_add(y)), x.overflowing_add(y).1)
+        );
+        assert_eq!(
+            a.overflowing_sub(b),
+            (from(x.wrapping_sub(y)), x.overflowing_sub(y).1)
+        );
+        assert_eq!(
+            a.overflowing_mul(b),
+            (from(x.wrapping_mul(y)), x.overflowing_mul(y).1)
+        );
+        assert_eq!(
+            a.overflowing_pow(exponent),
+            (
+                from(x.wrapping_pow(exponent)),
+                x.overflowing_pow(exponent).1
+            )
+        );
+        assert_eq!(
+            a.overflowing_shl(shift),
+            (from(x.wrapping_shl(shift)), x.overflowing_shl(shift).1)
+        );
+        assert_eq!(
+            a.overflowing_shr(shift),
+            (from(x.wrapping_shr(shift)), x.overflowing_shr(shift).1)
+        );
+        assert_eq!(
+            a.overflowing_neg(),
+            (from(x.wrapping_neg()), x.overflowing_neg().1)
+        );
+        assert_eq!(a.checked_div(b).map(to_i128), x.checked_div(y));
+        assert_eq!(a.checked_rem(b).map(to_i128), x.checked_rem(y));
+        assert_eq!(to_i128(a.saturating_add(b)), x.saturating_add(y));
+        assert_eq!(to_i128(a.saturating_sub(b)), x.saturating_sub(y));
+        assert_eq!(to_i128(a.saturating_mul(b)), x.saturating_mul(y));
+        assert_eq!(
+            to_i128(a.saturating_pow(exponent)),
+            x.saturating_pow(exponent)
+        );
+        assert_eq!(to_i128(a.saturating_neg()), x.saturating_neg());
+        assert_eq!(to_u128(a.unsigned_abs()), x.unsigned_abs());
+        assert_eq!(a.cmp(&b), x.cmp(&y));
+        assert_eq!(a.to_string(), x.to_string());
+        assert_eq!(format!("{a:X}"), format!("{x:X}"));
+        if y != 0 {
+            assert_eq!(to_i128(a.saturating_div(b)), x.saturating_div(y));
+        }
+    }
+}
+
+#[test]
+fn fixed_width_matches_bigint() {
+    let mut state = 0x9e37_79b9_7f4a_7c15;
+    let modulus = BigInt::from(2).pow(256);
+    let half = BigInt::from(2).pow(255);
+    let wrap = |value: BigInt| number_theory::rem_euclid(&value, &modulus);
+    let signed = |value: BigInt| {
+        let value = wrap(value);
+        if value >= half {
+            value - modulus.clone()
+        } else {
+            value
+        }
+    };
+
+    for _ in 0..300 {
+        let a = U256::from_limbs(next_limbs(&mut state));
+        let b = U256::from_limbs(next_limbs(&mut state));
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        let shift = (next_u64(&mut state) % 256) as u32;
+
+        assert_eq!(BigInt::from(a + b), wrap(x.clone() + y.clone()));
+        assert_eq!(BigInt::from(a - b), wrap(x.clone() - y.clone()));
+        assert_eq!(BigInt::from(a * b), wrap(x.clone() * y.clone()));
+        assert_eq!(
+            BigInt::from(a << shift),
+            wrap(x.clone() * BigInt::from(2).pow(shift))
+        );
+        assert_eq!(
+            BigInt::from(a >> shift),
+            x.clone() / BigInt::from(2).pow(shift)
+        );
+        if !b.is_zero() {
+            assert_eq!(BigInt::from(a / b), x.clone() / y.clone());
+            assert_eq!(BigInt::from(a % b), x.clone() % y.clone());
+        }
+        assert_eq!(U256::try_from(&x).unwrap(), a);
+        assert_eq!(a.to_string(), x.to_string());
+        assert_eq!(a.to_string().parse::<U256>().unwrap(), a);
+
+        let (a, b) = (a.cast_signed(), b.cast_signed());
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!(x, signed(BigInt::from(a.cast_unsigned())));
+
+        assert_eq!(BigInt::from(a + b), signed(x.clone() + y.clone()));
+        assert_eq!(BigInt::from(a - b), signed(x.clone() - y.clone()));
+        assert_eq!(BigInt::from(a * b), signed(x.clone() * y.clone()));
+        assert_eq!(
+            a.checked_mul(b).is_none(),
+            x.clone() * y.clone() != signed(x.clone() * y.clone())
+        );
+        let power = BigInt::from(2).pow(shift);
+        assert_eq!(
+            BigInt::from(a >> shift),
+            (x.clone() - number_theory::rem_euclid(&x, &power)) / power
+        );
+        if !b.is_zero() {
+            assert_eq!(BigInt::from(a / b), signed(x.clone() / y.clone()));
+            assert_eq!(BigInt::from(a % b), x.clone() % y.clone());
+        }
+        assert_eq!(I256::try_from(&x).unwrap(), a);
+        assert_eq!(a.to_string(), x.to_string());
+        assert_eq!(a.to_string().parse::<I256>().unwrap(), a);
+    }
+}
+
+#[test]
+fn fixed_width_edge_cases() {
+    assert_eq!(U256::MAX + 1u8, U256::ZERO);
+    assert_eq!(U256::ZERO - 1u8, U256::MAX);
+    assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+    assert_eq!(U256::MAX.saturating_add(U256::ONE), U256::MAX);
+    assert_eq!(U256::ONE.checked_neg(), None);
+    assert_eq!(U256::ZERO.checked_neg(), Some(U256::ZERO));
+    assert_eq!(U256::from(2u8).pow(255), U256::ONE << 255);
+    assert_eq!(U256::from(2u8).checked_pow(256), None);
+    assert_eq!(
+        BigInt::from(U256::from(3u8).pow(161)),
+        BigInt::from(3).pow(161)
+    );
+    assert_eq!(U256::ONE.overflowing_shl(256), (U256::ONE, true));
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(U512::MAX.count_ones(), 512);
+
+    assert_eq!(I256::MIN.checked_div(I256::from(-1)), None);
+    assert_eq!(I256::MIN.wrapping_div(I256::from(-1)), I256::MIN);
+    assert_eq!(I256::MIN.saturating_div(I256::from(-1)), I256::MAX);
+    assert_eq!(I256::MIN.checked_neg(), None);
+    assert_eq!(I256::MIN.unsigned_abs(), U256::ONE << 255);
+    assert_eq!(I256::from(-2).pow(255), I256::MIN);
+    assert_eq!(I256::from(-2).checked_pow(256), None);
+    assert_eq!(I256::from(-2).saturating_pow(257), I256::MIN);
+    assert_eq!(I256::from(-7) / 2, I256::from(-3));
+    assert_eq!(I256::from(-7) % 2, I256::from(-1));
+    assert_eq!(I256::from(-7) >> 1, I256::from(-4));
+    assert_eq!(I256::from(-5).signum(), -1);
+    assert!(I256::MIN < I256::from(-1) && I256::from(-1) < I256::ZERO && I256::ZERO < I256::MAX);
+    assert!(I256::from(3) > 2);
+
+    let mut x = I1024::from(10);
+    x *= 3;
+    x -= 40;
+    x <<= 2;
+    x /= I1024::from(5);
+    assert_eq!(x, -8);
+    assert_eq!(BigInt::from(I1024::MIN), -BigInt::from(2).pow(1023));
+
+    assert_eq!(format!("{}", I256::from(-42)), "-42");
+    assert_eq!(format!("{:+}", U256::from(42u8)), "+42");
+    assert_eq!(format!("{:>6}", I256::from(-42)), "   -42");
+    assert_eq!(format!("{:#x}", U256::from(255u8)), "0xff");
+    assert_eq!(format!("{:x}", I256::from(-1)), "f".repeat(64));
+    assert_eq!(format!("{:b}", U256::ZERO), "0");
+
+    let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(max.parse::<U256>().unwrap(), U256::MAX);
+    assert!(matches!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            .parse::<U256>(),
+        Err(BigIntError::LargeNumber)
+    ));
+    let min = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+    assert_eq!(min.parse::<I256>().unwrap(), I256::MIN);
+    assert!(matches!(
+        min[1..].parse::<I256>(),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!("".parse::<U256>(), Err(BigIntError::NaN)));
+    assert!(matches!("12a".parse::<U256>(), Err(BigIntError::NaN)));
+    assert!(matches!("--1".parse::<I256>(), Err(BigIntError::NaN)));
+    assert!(matches!("+5".parse::<U256>(), Err(BigIntError::NaN)));
+    assert!(matches!("+5".parse::<I256>(), Err(BigIntError::NaN)));
+    assert!(matches!("-+5".parse::<I256>(), Err(BigIntError::NaN)));
+
+    assert!(matches!(
+        U256::try_from(BigInt::from(-1)),
+        Err(BigIntError::NegativeNumber)
+    ));
+    assert!(matches!(
+        U256::try_from(BigInt::from(2).pow(256)),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        I256::try_from(BigInt::from(2).pow(255)),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        I256::try_from(-BigInt::from(2).pow(255) - 1),
+        Err(BigIntError::SmallNumber)
+    ));
+    assert!(matches!(
+        I256::try_from(-BigInt::from(2).pow(300)),
+        Err(BigIntError::SmallNumber)
+    ));
+    assert_eq!(
+        I256::try_from(-BigInt::from(2).pow(255)).unwrap(),
+        I256::MIN
+    );
+}
+
+#[test]
+fn fixed_width_long_division() {
+    let check = |a: U256, b: U256| {
+        let (quotient, remainder) = a.div_rem(b);
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!(BigInt::from(quotient), x.clone() / y.clone());
+        assert_eq!(BigInt::from(remainder), x % y);
+    };
+
+    //the first estimate is one too large and the divisor gets added back
+    check(
+        U256::from_limbs([0, 0, 1 << 63, (1 << 63) - 1]),
+        U256::from_limbs([1, 0, 1 << 63, 0]),
+    );
+    //the estimate gets corrected from the second divisor limb
+    check(
+        U256::from_limbs([0, u64::MAX - 1, 0, 1 << 63]),
+        U256::from_limbs([u64::MAX, 1 << 63, 0, 0]),
+    );
+    check(U256::MAX, U256::from_limbs([u64::MAX, u64::MAX, 0, 0]));
+    check(U256::MAX, U256::MAX);
+    check(U256::from(5u8), U256::MAX);
+
+    let mut state = 0x0bad_5eed_dead_beef;
+    for _ in 0..200 {
+        let a = U1024::from_limbs(next_limbs(&mut state));
+        let b = U1024::from_limbs(next_limbs(&mut state));
+        if b.is_zero() {
+            continue;
+        }
+
+        let (quotient, remainder) = a.div_rem(b);
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!(BigInt::from(quotient), x.clone() / y.clone());
+        assert_eq!(BigInt::from(remainder), x % y);
+    }
+}
+
+#[test]
+#[should_panic]
+fn fixed_width_division_by_zero() {
+    let _ = U256::ONE / U256::ZERO;
+}
+
+#[test]
+fn wrapping_bit_arithmetic() {
+    let mut state = 0x1234_5678_9abc_def1;
+    for _ in 0..500 {
+        let (x, y) = (next_u64(&mut state) as i64, next_u64(&mut state) as i64);
+        let (a, b) = (BigInt::from(x), BigInt::from(y));
+        let shift = next_u64(&mut state) % 70;
+        let exponent = (next_u64(&mut state) % 20) as u32;
+
+        assert_eq!(a.wrapping_to_bits(64), x as u64);
+        assert_eq!(a.truncate_signed(64), x);
+        assert_eq!(a.truncate_signed(32), x as i32);
+        assert_eq!(a.truncate_signed(8), x as i8);
+        assert_eq!(a.wrapping_add_bits(b.clone(), 64), x.wrapping_add(y) as u64);
+        assert_eq!(a.wrapping_sub_bits(b.clone(), 64), x.wrapping_sub(y) as u64);
+        assert_eq!(a.wrapping_mul_bits(b.clone(), 64), x.wrapping_mul(y) as u64);
+        assert_eq!(a.wrapping_mul_bits(b.clone(), 16), x.wrapping_mul(y) as u16);
+        assert_eq!(a.wrapping_neg_bits(64), x.wrapping_neg() as u64);
+        assert_eq!(
+            a.wrapping_pow_bits(exponent, 64),
+            x.wrapping_pow(exponent) as u64
+        );
+        assert_eq!(
+            a.wrapping_shl_bits(shift, 64),
+            (x as u64).checked_shl(shift as u32).unwrap_or(0)
+        );
+        assert_eq!(
+            a.wrapping_shr_bits(shift, 64),
+            (x as u64).checked_shr(shift as u32).unwrap_or(0)
+        );
+    }
+
+    //80 bit registers
+    let max = BigInt::from(2).pow(80) - 1;
+    assert_eq!(max.wrapping_add_bits(1, 80), 0);
+    assert_eq!(max.truncate_signed(80), -1);
+    assert_eq!(BigInt::from(0).wrapping_sub_bits(1, 80), max);
+    assert_eq!(
+        BigInt::from(2).pow(79).truncate_signed(80),
+        -BigInt::from(2).pow(79)
+    );
+    assert_eq!(
+        BigInt::from(-1).wrapping_to_bits(96),
+        BigInt::from(2).pow(96) - 1
+    );
+    assert_eq!(
+        BigInt::from(3).wrapping_pow_bits(200, 96),
+        BigInt::from(3).pow(200).wrapping_to_bits(96)
+    );
+    assert_eq!(BigInt::from(5).wrapping_to_bits(0), 0);
+    assert_eq!(BigInt::from(5).truncate_signed(0), 0);
+    assert_eq!(BigInt::from(1).truncate_signed(1), -1);
+}
+
+// generic code written against num-traits only
+fn sum_of_squares<T: num_traits::Num + Clone>(values: &[T]) -> T {
+    values
+        .iter()
+        .fold(T::zero(), |sum, value| sum + value.clone() * value.clone())
+}
+
+#[test]
+fn num_traits_coverage() {
+    use num_traits::{
+        CheckedDiv, CheckedNeg, CheckedRem, CheckedSub, FromPrimitive, Num, One, Signed,
+        ToPrimitive, Zero,
+    };
+
+    let values: Vec<BigInt> = [3, -4, 12].iter().map(|&v| BigInt::from(v)).collect();
+    assert_eq!(sum_of_squares(&values), 169);
+    assert!(BigInt::zero().is_zero());
+    assert!(!BigInt::one().is_zero());
+    assert_eq!(BigInt::one(), 1);
+    assert_eq!(
+        sum_of_squares(&[BigUint::from(5_u8), BigUint::one()]),
+        26_u8
+    );
+
+    assert_eq!(BigInt::from_str_radix("ff", 16).unwrap(), 255);
+    assert_eq!(BigInt::from_str_radix("-Zz", 36).unwrap(), -1295);
+    assert_eq!(BigInt::from_str_radix("+101", 2).unwrap(), 5);
+    assert_eq!(BigUint::from_str_radix("+ff", 16).unwrap(), 255_u8);
+    assert!(matches!(
+        BigInt::from_str_radix("+", 2),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(
+        BigInt::from_str_radix("+-1", 2),
+        Err(BigIntError::NaN)
+    ));
+    assert_eq!(
+        BigInt::from_str_radix("123456789abcdef0123456789", 16).unwrap(),
+        BigInt::from_str("90144042682896311822508713865").unwrap()
+    );
+    assert!(matches!(
+        BigInt::from_str_radix("12", 2),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(
+        BigInt::from_str_radix("-", 10),
+        Err(BigIntError::NaN)
+    ));
+    assert!(matches!(
+        BigInt::from_str_radix("", 10),
+        Err(BigIntError::NaN)
+    ));
+    assert_eq!(BigUint::from_str_radix("777", 8).unwrap(), 511_u16);
+    assert!(matches!(
+        BigUint::from_str_radix("-1", 8),
+        Err(BigIntError::NegativeNumber)
+    ));
+
+    let (a, b) = (BigInt::from(-7), BigInt::from(3));
+    assert_eq!(Signed::abs(&a), 7);
+    assert_eq!(a.abs_sub(&b), 0);
+    assert_eq!(b.abs_sub(&a), 10);
+    assert_eq!(a.signum(), -1);
+    assert_eq!(BigInt::zero().signum(), 0);
+    assert!(a.is_negative() && !a.is_positive());
+    assert!(!BigInt::zero().is_negative() && !BigInt::zero().is_positive());
+
+    assert_eq!(a.checked_sub(&b).unwrap(), -10);
+    assert_eq!(a.checked_div(&b).unwrap(), -2);
+    assert_eq!(a.checked_rem(&b).unwrap(), -1);
+    assert!(a.checked_div(&BigInt::zero()).is_none());
+    assert!(a.checked_rem(&BigInt::zero()).is_none());
+    assert_eq!(a.checked_neg().unwrap(), 7);
+
+    for value in [
+        0,
+        1,
+        -1,
+        i64::MAX as i128,
+        i64::MIN as i128,
+        i128::MAX,
+        i128::MIN,
+    ] {
+        let number = BigInt::from(value);
+        assert_eq!(number.to_i128(), Some(value));
+        assert_eq!(number.to_i64(), i64::try_from(value).ok());
+        assert_eq!(number.to_u64(), u64::try_from(value).ok());
+        assert_eq!(number.to_u128(), u128::try_from(value).ok());
+        assert_eq!(ToPrimitive::to_f64(&number), Some(value as f64));
+        assert_eq!(BigInt::from_i128(value).unwrap(), number);
+    }
+    assert_eq!(BigInt::from(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(BigInt::from(u128::MAX).to_i128(), None);
+    assert_eq!((BigInt::from(u128::MAX) + 1).to_u128(), None);
+    assert_eq!((BigInt::from(i128::MIN) - 1).to_i128(), None);
+    assert_eq!(
+        ToPrimitive::to_f64(&BigInt::from(2).pow(2000)),
+        Some(f64::INFINITY)
+    );
+
+    assert_eq!(BigInt::from_f64(-2.9).unwrap(), -2);
+    assert_eq!(BigInt::from_f64(1e30).unwrap(), BigInt::from(1e30 as u128));
+    assert!(BigInt::from_f64(f64::NAN).is_none());
+    assert!(BigInt::from_f64(f64::INFINITY).is_none());
+    assert_eq!(num_traits::cast::<_, BigInt>(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(num_traits::cast::<_, BigInt>(-5_i8).unwrap(), -5);
+    assert_eq!(
+        num_traits::cast::<_, BigInt>(1e300).unwrap(),
+        BigInt::from_f64(1e300).unwrap()
+    );
+    assert!(num_traits::cast::<_, BigInt>(f64::NAN).is_none());
+}
+
+#[test]
+fn num_integer_coverage() {
+    use num_integer::{Integer, Roots};
+    use num_traits::Euclid;
+
+    let mut state = 0x0f1e_2d3c_4b5a_6978;
+    for _ in 0..300 {
+        let x = (next_u64(&mut state) >> 40) as i64 - (1 << 23);
+        let y = (next_u64(&mut state) >> 52) as i64 - (1 << 11);
+        let (a, b) = (BigInt::from(x), BigInt::from(y));
+
+        if y != 0 {
+            assert_eq!(a.div_floor(&b), Integer::div_floor(&x, &y));
+            assert_eq!(a.mod_floor(&b), x.mod_floor(&y));
+            assert_eq!(a.div_rem(&b), (BigInt::from(x / y), BigInt::from(x % y)));
+            assert_eq!(Euclid::div_euclid(&a, &b), x.div_euclid(y));
+            assert_eq!(Euclid::rem_euclid(&a, &b), x.rem_euclid(y));
+            assert_eq!(Integer::is_multiple_of(&a, &b), x % y == 0);
+        }
+        assert_eq!(Integer::gcd(&a, &b), x.gcd(&y));
+        assert_eq!(a.lcm(&b), x.lcm(&y));
+        assert_eq!(Integer::is_even(&a), x % 2 == 0);
+        assert_eq!(a.is_odd(), x % 2 != 0);
+        assert_eq!(a.cbrt(), x.cbrt());
+        assert_eq!(a.nth_root(5), x.nth_root(5));
+        assert_eq!(Roots::sqrt(&a.abs()), x.abs().sqrt());
+        assert_eq!(a.abs().nth_root(4), x.abs().nth_root(4));
+    }
+
+    assert!(Integer::is_multiple_of(&BigInt::from(0), &BigInt::from(0)));
+    assert!(!Integer::is_multiple_of(&BigInt::from(3), &BigInt::from(0)));
+    let big = BigInt::from(10).pow(60);
+    assert_eq!(big.nth_root(3), BigInt::from(10).pow(20));
+    assert_eq!((big.clone() - 1).nth_root(3), BigInt::from(10).pow(20) - 1);
+    assert_eq!(Roots::sqrt(&big), BigInt::from(10).pow(30));
+    assert_eq!(big.nth_root(1), big);
+    assert_eq!(big.nth_root(200), 1);
+    assert_eq!(BigInt::from(7).max(BigInt::from(-9)), 7);
+}
+
+#[test]
+#[should_panic]
+fn even_root_of_negative_number() {
+    use num_integer::Roots;
+
+    let _ = BigInt::from(-16).nth_root(2);
+}
+
+#[test]
+fn float_conversions() {
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..500 {
+        let value = (((next_u64(&mut state) as i128) << 64) | next_u64(&mut state) as i128)
+            >> (next_u64(&mut state) % 128);
+        let number = BigInt::from(value);
+        let float = value as f64;
+
+        assert_eq!(number.to_f64(), float);
+        assert_eq!(number.to_f32(), value as f32);
+        assert_eq!(BigInt::from(value as u128).to_f64(), value as u128 as f64);
+        assert_eq!(
+            BigInt::try_from(float).unwrap(),
+            BigInt::from(float as i128)
+        );
+        assert_eq!(
+            number == float,
+            float as i128 == value && float != 2f64.powi(127)
+        );
+        assert_eq!(
+            number.partial_cmp(&float),
+            BigFloat::from(&number).partial_cmp(&BigFloat::try_from(float).unwrap())
+        );
+    }
+
+    //ties round to even at the top of the range
+    let max = BigInt::from_str("179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792").unwrap();
+    assert_eq!(max.to_f64(), f64::INFINITY);
+    assert_eq!((max.clone() - 1).to_f64(), f64::MAX);
+    assert_eq!((-max).to_f64(), f64::NEG_INFINITY);
+    let max = BigInt::from_str("340282356779733661637539395458142568448").unwrap();
+    assert_eq!(max.to_f32(), f32::INFINITY);
+    assert_eq!((max - 1).to_f32(), f32::MAX);
+    assert_eq!(BigInt::from(2).pow(5000).to_f64(), f64::INFINITY);
+    assert_eq!(BigInt::from(0).to_f64(), 0.0);
+    assert_eq!((BigInt::from(2).pow(53) + 1).to_f64(), 9007199254740992.0);
+    assert_eq!((BigInt::from(2).pow(53) + 3).to_f64(), 9007199254740996.0);
+    assert_eq!(BigInt::from(16777217).to_f32(), 16777216.0);
+
+    let exact = BigInt::from_str("1000000000000000052504760255204420248704468581108159154915854115511802457988908195786371375080447864043704443832883878176942523235360430575644792184786706982848387200926575803737830233794788090059368953234970799945081119038967640880074652742780142494579258788820056842838115669472196386865459400540160").unwrap();
+    assert_eq!(BigInt::try_from(1e300).unwrap(), exact);
+    assert_eq!(BigInt::try_from(-1e300_f64).unwrap(), -exact.clone());
+    assert_eq!(exact.to_f64(), 1e300);
+    assert_eq!(BigInt::try_from(-0.0).unwrap(), 0);
+    assert_eq!(BigInt::try_from(16777216_f32).unwrap(), 16777216);
+    assert!(matches!(
+        BigInt::try_from(2.5),
+        Err(BigIntError::FractionalNumber)
+    ));
+    assert!(matches!(BigInt::try_from(f64::NAN), Err(BigIntError::NaN)));
+    assert!(matches!(
+        BigInt::try_from(f64::INFINITY),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        BigInt::try_from(f64::NEG_INFINITY),
+        Err(BigIntError::SmallNumber)
+    ));
+    assert_eq!(BigInt::from_f64_truncated(-2.7).unwrap(), -2);
+    assert_eq!(BigInt::from_f64_truncated(1e-300).unwrap(), 0);
+    assert_eq!(
+        BigInt::from_f64_rounded(2.5, RoundingMode::HalfEven).unwrap(),
+        2
+    );
+    assert_eq!(
+        BigInt::from_f64_rounded(2.5, RoundingMode::HalfUp).unwrap(),
+        3
+    );
+    assert_eq!(
+        BigInt::from_f64_rounded(-2.5, RoundingMode::Floor).unwrap(),
+        -3
+    );
+    assert_eq!(
+        BigInt::from_f64_rounded(-2.5, RoundingMode::Ceiling).unwrap(),
+        -2
+    );
+    assert!(matches!(
+        BigInt::from_f64_truncated(f64::NAN),
+        Err(BigIntError::NaN)
+    ));
+
+    let three = BigInt::from(3);
+    assert!(three == 3.0 && three != 3.5);
+    assert!(three < 3.5 && three > 2.999 && three <= 3.0 && three >= 3.0_f32);
+    assert!(three < f64::INFINITY && three > f64::NEG_INFINITY);
+    assert!(three.partial_cmp(&f64::NAN).is_none());
+    assert!(BigInt::from(0) == -0.0);
+    let above = BigInt::from(2).pow(53) + 1;
+    assert!(above != 9007199254740992.0 && above > 9007199254740992.0);
+    assert!(above < 9007199254740994.0);
+    assert!(-BigInt::from(2).pow(2000) < f64::MIN);
+    assert!(BigInt::from(2).pow(2000) > f64::MAX);
+    assert!(BigInt::from(16777217) > 16777216_f32);
+    assert_eq!(
+        format!("{}", BigIntError::FractionalNumber),
+        "Not an integer"
+    );
+}
+
+#[test]
+fn byte_conversions() {
+    let mut state = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..300 {
+        let value = (((next_u64(&mut state) as i128) << 64) | next_u64(&mut state) as i128)
+            >> (next_u64(&mut state) % 128);
+        let number = BigInt::from(value);
+
+        assert_eq!(
+            number.to_signed_bytes_be_padded(16).unwrap(),
+            value.to_be_bytes()
+        );
+        assert_eq!(
+            number.to_signed_bytes_le_padded(16).unwrap(),
+            value.to_le_bytes()
+        );
+        assert_eq!(BigInt::from_signed_bytes_be(&value.to_be_bytes()), value);
+        assert_eq!(BigInt::from_signed_bytes_le(&value.to_le_bytes()), value);
+
+        //the shortest encoding can't lose its first byte
+        let bytes = number.to_signed_bytes_be();
+        assert_eq!(BigInt::from_signed_bytes_be(&bytes), value);
+        assert!(bytes.len() == 1 || BigInt::from_signed_bytes_be(&bytes[1..]) != value);
+        assert_eq!(
+            BigInt::from_signed_bytes_le(&number.to_signed_bytes_le()),
+            value
+        );
+
+        let magnitude = value.unsigned_abs().to_be_bytes();
+        assert_eq!(
+            number.to_bytes_be_padded(16).unwrap(),
+            (value >= 0, magnitude.to_vec())
+        );
+        let (positive, bytes) = number.to_bytes_le();
+        assert_eq!(BigInt::from_bytes_le(positive, &bytes), value);
+        let (positive, bytes) = number.to_bytes_be();
+        assert!(bytes.len() == 1 || bytes[0] != 0);
+        assert_eq!(BigInt::from_bytes_be(positive, &bytes), value);
+        assert_eq!(BigInt::from_bytes_be(value >= 0, &magnitude), value);
+    }
+
+    assert_eq!(BigInt::from(0).to_bytes_be(), (true, vec![0]));
+    assert_eq!(BigInt::from(255).to_bytes_be(), (true, vec![255]));
+    assert_eq!(BigInt::from(-256).to_bytes_le(), (false, vec![0, 1]));
+    assert_eq!(BigInt::from_bytes_be(false, &[]), 0);
+    assert_eq!(
+        BigInt::from_bytes_be(false, &[0, 0]).to_bytes_be(),
+        (true, vec![0])
+    );
+    assert_eq!(
+        BigInt::from(258).to_bytes_le_padded(4).unwrap(),
+        (true, vec![2, 1, 0, 0])
+    );
+    assert!(matches!(
+        BigInt::from(256).to_bytes_be_padded(1),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert_eq!(
+        BigInt::from(0).to_bytes_be_padded(0).unwrap(),
+        (true, vec![])
+    );
+
+    assert_eq!(BigInt::from(0).to_signed_bytes_be(), [0]);
+    assert_eq!(BigInt::from(127).to_signed_bytes_be(), [0x7f]);
+    assert_eq!(BigInt::from(128).to_signed_bytes_be(), [0x00, 0x80]);
+    assert_eq!(BigInt::from(-128).to_signed_bytes_be(), [0x80]);
+    assert_eq!(BigInt::from(-129).to_signed_bytes_be(), [0xff, 0x7f]);
+    assert_eq!(BigInt::from(-129).to_signed_bytes_le(), [0x7f, 0xff]);
+    assert_eq!(
+        BigInt::from(-1).to_signed_bytes_be_padded(3).unwrap(),
+        [0xff; 3]
+    );
+    assert!(matches!(
+        BigInt::from(128).to_signed_bytes_be_padded(1),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert!(matches!(
+        BigInt::from(-129).to_signed_bytes_le_padded(1),
+        Err(BigIntError::SmallNumber)
+    ));
+    assert!(matches!(
+        BigInt::from(1).to_signed_bytes_be_padded(0),
+        Err(BigIntError::LargeNumber)
+    ));
+    assert_eq!(BigInt::from_signed_bytes_be(&[]), 0);
+    assert_eq!(BigInt::from_signed_bytes_be(&[0xff, 0x00]), -256);
+    assert_eq!(BigInt::from_signed_bytes_le(&[0x00, 0x80]), -32768);
+
+    let big = -BigInt::from(2).pow(1000) + 12345;
+    assert_eq!(BigInt::from_signed_bytes_be(&big.to_signed_bytes_be()), big);
+    assert_eq!(big.to_signed_bytes_be().len(), 126);
+    let (positive, bytes) = big.to_bytes_le();
+    assert_eq!(BigInt::from_bytes_le(positive, &bytes), big);
+}
@@ -0,0 +1,339 @@
+use crate::float::{bit_length, power_of_two};
+use crate::number_theory::{gcd, isqrt, rem_euclid};
+use crate::{BigInt, BigIntError, BigUint};
+
+use num_integer::{Integer, Roots};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid, FromPrimitive,
+    Num, One, Pow, Signed, ToPrimitive, Unsigned, Zero,
+};
+
+// num-traits and num-integer coverage so generic numeric code accepts BigInt and BigUint
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude == 0_u8
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+}
+
+impl Num for BigInt {
+    type FromStrRadixErr = BigIntError;
+
+    /// Digits 0-9 and a-z (either case) with an optional sign, '+' included like the primitive
+    /// from_str_radix. Panics on a radix outside 2..=36.
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        assert!((2..=36).contains(&radix), "radix has to be in 2..=36!");
+
+        let (positive, digits) = match string.strip_prefix('-') {
+            Some(digits) => (false, digits),
+            None => (true, string.strip_prefix('+').unwrap_or(string)),
+        };
+
+        //empty string or lone sign
+        if digits.is_empty() {
+            return Err(BigIntError::NaN);
+        }
+
+        let mut result = BigInt::default();
+        for char in digits.chars() {
+            let digit = char.to_digit(radix).ok_or(BigIntError::NaN)?;
+            result = result * radix + digit;
+        }
+
+        Ok(BigInt::from_magnitude(positive, result.magnitude))
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> Self {
+        BigInt::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            BigInt::default()
+        } else {
+            self.clone() - other.clone()
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if self.is_zero() {
+            BigInt::default()
+        } else if self.positive {
+            BigInt::from(1)
+        } else {
+            BigInt::from(-1)
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.positive && !self.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        !self.positive
+    }
+}
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self.clone() + v.clone())
+    }
+}
+
+impl CheckedSub for BigInt {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self.clone() - v.clone())
+    }
+}
+
+impl CheckedMul for BigInt {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self.clone() * v.clone())
+    }
+}
+
+impl CheckedDiv for BigInt {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+
+        Some(self.clone() / v.clone())
+    }
+}
+
+impl CheckedRem for BigInt {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+
+        Some(self.clone() % v.clone())
+    }
+}
+
+impl CheckedNeg for BigInt {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self.clone())
+    }
+}
+
+impl ToPrimitive for BigInt {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|number| i64::try_from(number).ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|number| u64::try_from(number).ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+
+        if self.positive {
+            i128::try_from(magnitude).ok()
+        } else {
+            //i128::MIN has no positive counterpart
+            0_i128.checked_sub_unsigned(magnitude)
+        }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        if !self.positive {
+            return None;
+        }
+
+        self.magnitude.to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(BigInt::to_f32(self))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(BigInt::to_f64(self))
+    }
+}
+
+impl FromPrimitive for BigInt {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    /// Truncates toward zero, None for NaN and infinities.
+    fn from_f64(n: f64) -> Option<Self> {
+        BigInt::from_f64_truncated(n).ok()
+    }
+}
+
+impl num_traits::NumCast for BigInt {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_i128()
+            .map(Into::into)
+            .or_else(|| n.to_u128().map(Into::into))
+            .or_else(|| n.to_f64().and_then(BigInt::from_f64))
+    }
+}
+
+impl Euclid for BigInt {
+    fn div_euclid(&self, v: &Self) -> Self {
+        let (quotient, remainder) = BigInt::divide_with_remainder(self.clone(), v.clone());
+
+        //the remainder has to end up non-negative
+        match (remainder.positive, v.positive) {
+            (true, _) => quotient,
+            (false, true) => quotient - 1,
+            (false, false) => quotient + 1,
+        }
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        if v.is_zero() {
+            panic!("division by zero!");
+        }
+
+        rem_euclid(self, v)
+    }
+}
+
+impl Integer for BigInt {
+    fn div_floor(&self, other: &Self) -> Self {
+        self.div_mod_floor(other).0
+    }
+
+    fn mod_floor(&self, other: &Self) -> Self {
+        self.div_mod_floor(other).1
+    }
+
+    fn div_mod_floor(&self, other: &Self) -> (Self, Self) {
+        let (quotient, remainder) = BigInt::divide_with_remainder(self.clone(), other.clone());
+
+        //the remainder takes the sign of the divisor
+        if !remainder.is_zero() && remainder.positive != other.positive {
+            (quotient - 1, remainder + other.clone())
+        } else {
+            (quotient, remainder)
+        }
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        gcd(self, other)
+    }
+
+    fn lcm(&self, other: &Self) -> Self {
+        //zero edgecase
+        if self.is_zero() || other.is_zero() {
+            return BigInt::default();
+        }
+
+        (self.clone() / gcd(self, other) * other.clone()).abs()
+    }
+
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        //only zero is a multiple of zero
+        if other.is_zero() {
+            return self.is_zero();
+        }
+
+        (self.clone() % other.clone()).is_zero()
+    }
+
+    fn is_even(&self) -> bool {
+        BigInt::is_even(self)
+    }
+
+    fn is_odd(&self) -> bool {
+        !BigInt::is_even(self)
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        if other.is_zero() {
+            panic!("division by zero!");
+        }
+
+        BigInt::divide_with_remainder(self.clone(), other.clone())
+    }
+}
+
+impl Roots for BigInt {
+    /// Truncated toward zero, panics on a zero degree or an even root of a negative number.
+    fn nth_root(&self, n: u32) -> Self {
+        assert!(n != 0, "zeroth root!");
+
+        if !self.positive {
+            assert!(n % 2 == 1, "even root of a negative number!");
+            return -self.abs().nth_root(n);
+        }
+
+        //small edgecases
+        if n == 1 || *self < 2 {
+            return self.clone();
+        }
+
+        //Newton's method from 2^ceil(bits / n), which is always above the root
+        let mut root = power_of_two(bit_length(self).div_ceil(n as u64));
+        loop {
+            let next = (root.clone() * (n - 1) + self.clone() / root.clone().pow(n - 1)) / n;
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    fn sqrt(&self) -> Self {
+        assert!(self.positive, "square root of a negative number!");
+
+        isqrt(self)
+    }
+}
+
+impl Zero for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0_u8
+    }
+}
+
+impl One for BigUint {
+    fn one() -> Self {
+        BigUint::from(1_u8)
+    }
+}
+
+impl Num for BigUint {
+    type FromStrRadixErr = BigIntError;
+
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        BigInt::from_str_radix(string, radix)?.try_into()
+    }
+}
+
+impl Unsigned for BigUint {}
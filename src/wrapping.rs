@@ -0,0 +1,89 @@
+use crate::BigInt;
+use crate::float::power_of_two;
+use crate::number_theory::{mod_pow, rem_euclid};
+
+// n-bit machine arithmetic, results are register values in [0, 2^n), truncate_signed reads
+// them as two's complement since the low bits don't depend on the signedness
+impl BigInt {
+    /// self mod 2^bits, in [0, 2^bits).
+    pub fn wrapping_to_bits(&self, bits: u64) -> BigInt {
+        rem_euclid(self, &power_of_two(bits))
+    }
+
+    /// The low `bits` bits read as two's complement, in [-2^(bits - 1), 2^(bits - 1)).
+    pub fn truncate_signed(&self, bits: u64) -> BigInt {
+        //zero width edgecase
+        if bits == 0 {
+            return BigInt::default();
+        }
+
+        let value = self.wrapping_to_bits(bits);
+        if value >= power_of_two(bits - 1) {
+            value - power_of_two(bits)
+        } else {
+            value
+        }
+    }
+
+    /// (self + rhs) mod 2^bits.
+    pub fn wrapping_add_bits<T>(&self, rhs: T, bits: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        (self.clone() + rhs).wrapping_to_bits(bits)
+    }
+
+    /// (self - rhs) mod 2^bits.
+    pub fn wrapping_sub_bits<T>(&self, rhs: T, bits: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        (self.clone() - rhs).wrapping_to_bits(bits)
+    }
+
+    /// (self * rhs) mod 2^bits.
+    pub fn wrapping_mul_bits<T>(&self, rhs: T, bits: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        //only the low bits of the factors matter
+        let rhs: BigInt = rhs.into();
+        (self.wrapping_to_bits(bits) * rhs.wrapping_to_bits(bits)).wrapping_to_bits(bits)
+    }
+
+    /// -self mod 2^bits.
+    pub fn wrapping_neg_bits(&self, bits: u64) -> BigInt {
+        (-self.clone()).wrapping_to_bits(bits)
+    }
+
+    /// (self << shift) mod 2^bits, shifts of at least `bits` give zero.
+    pub fn wrapping_shl_bits(&self, shift: u64, bits: u64) -> BigInt {
+        //everything is shifted out
+        if shift >= bits {
+            return BigInt::default();
+        }
+
+        (self.wrapping_to_bits(bits - shift) * power_of_two(shift)).wrapping_to_bits(bits)
+    }
+
+    /// Logical shift of the n-bit register value, shifts of at least `bits` give zero.
+    pub fn wrapping_shr_bits(&self, shift: u64, bits: u64) -> BigInt {
+        //everything is shifted out
+        if shift >= bits {
+            return BigInt::default();
+        }
+
+        self.wrapping_to_bits(bits) / power_of_two(shift)
+    }
+
+    /// self^exponent mod 2^bits, panics on a negative exponent.
+    pub fn wrapping_pow_bits<T>(&self, exponent: T, bits: u64) -> BigInt
+    where
+        T: Into<BigInt>,
+    {
+        let exponent = exponent.into();
+        assert!(exponent >= 0, "negative exponent!");
+
+        mod_pow(self, &exponent, &power_of_two(bits))
+    }
+}
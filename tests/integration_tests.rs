@@ -17,7 +17,26 @@ fn catalan_number() {
         calculated_numbers.push(result);
     }
 
-    let catalan_numbers: Vec<BigInt> = vec![
+    let catalan_numbers = known_catalan_numbers();
+
+    let result_check = calculated_numbers.iter().zip(catalan_numbers.iter());
+
+    for result in result_check {
+        assert_eq!(result.0, result.1);
+    }
+}
+
+#[test]
+fn catalan_number_closed_form() {
+    let catalan_numbers = known_catalan_numbers();
+
+    for (n, expected) in catalan_numbers.iter().enumerate() {
+        assert_eq!(&BigInt::catalan(n as u64), expected);
+    }
+}
+
+fn known_catalan_numbers() -> Vec<BigInt> {
+    vec![
         BigInt::from_str("1").unwrap(),
         BigInt::from_str("1").unwrap(),
         BigInt::from_str("2").unwrap(),
@@ -119,11 +138,5 @@ fn catalan_number() {
         BigInt::from_str("57743358069601357782187700608042856334020731624756611000").unwrap(),
         BigInt::from_str("227508830794229349661819540395688853956041682601541047340").unwrap(),
         BigInt::from_str("896519947090131496687170070074100632420837521538745909320").unwrap(),
-    ];
-
-    let result_check = calculated_numbers.iter().zip(catalan_numbers.iter());
-
-    for result in result_check {
-        assert_eq!(result.0, result.1);
-    }
+    ]
 }